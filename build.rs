@@ -15,155 +15,25 @@
 
 //! Enumerations of well-known SS58 account types and tokens used in the Polkadot ecosystem.
 use proc_macro2::Ident;
-use quote::{format_ident, quote};
-use serde::Deserialize;
-use std::{
-	collections::{btree_map::Entry, BTreeMap, HashMap},
-	env, fs,
-	path::Path,
-};
-use unicode_xid::UnicodeXID;
-
-#[derive(Deserialize)]
-struct Registry {
-	#[serde(rename = "registry")]
-	accounts: Vec<AccountType>,
-}
+use quote::{format_ident, quote, ToTokens};
+use std::{env, fs, path::Path};
 
-fn is_valid_rust_identifier(id: &str) -> Result<(), String> {
-	if let Some(ch) = id.chars().next() {
-		if ch.is_xid_start() {
-			for ch in id.chars().skip(1) {
-				if !ch.is_xid_continue() {
-					return Err(format!("Invalid char `{}` in `{}`", ch, id))
-				}
-			}
-			Ok(())
-		} else {
-			Err(format!("`{}` starts with `{}` which is not valid at the start", id, ch))
-		}
-	} else {
-		Err("empty identifier".into())
-	}
-}
+#[path = "src/hex_decode.rs"]
+mod hex_decode;
+#[allow(dead_code)]
+#[path = "src/json.rs"]
+mod json;
 
-impl Registry {
-	pub fn validate(self) -> Result<(Vec<AccountType>, Vec<TokenType>), String> {
-		let mut tokens = BTreeMap::<String, TokenType>::new();
-		let mut used_prefixes = HashMap::<u16, AccountType>::new();
-		let mut used_networks = HashMap::<String, AccountType>::new();
-		for account_type in &self.accounts {
-			if let Some(clash) = used_prefixes.insert(account_type.prefix, (*account_type).clone())
-			{
-				return Err(format!(
-                    "prefixes must be unique but this account's prefix:\n{:#?}\nclashed with\n{:#?}",
-                    account_type,
-                    clash
-                ));
-			}
-			if let Some(clash) = used_networks.insert(account_type.name(), account_type.clone()) {
-				return Err(format!(
-                    "networks must be unique but this account's network:\n{:#?}\nclashed with\n:{:#?}",
-                    account_type,
-                    clash
-                ));
-			}
-			if account_type.network.is_empty() {
-				return Err("network is mandatory.".into())
-			}
-
-			if let Err(err) = is_valid_rust_identifier(&account_type.name()) {
-				return Err(format!("network not valid: {} for {:#?}", err, account_type))
-			}
-			if account_type.decimals.len() != account_type.symbols.len() {
-				return Err(format!(
-					"decimals must be specified for each symbol: {:?}",
-					account_type
-				))
-			}
-			for (name, decimals) in account_type.symbols.iter().zip(&account_type.decimals) {
-				use Entry::*;
-				let network = account_type.display_name.clone();
-				match tokens.entry(name.to_owned()) {
-					Occupied(mut e) => {
-						if e.get().decimals != *decimals {
-							return Err(format!(
-								"Inconsistent decimals specified for token {}.\nPrevious networks: {}\nCurrent network: {}",
-								name,
-								e.get().networks.join(", "),
-								network,
-							))
-						}
-						e.get_mut().networks.push(network);
-					},
-					Vacant(e) => {
-						e.insert(TokenType {
-							symbol: name.to_owned(),
-							decimals: *decimals,
-							networks: vec![network],
-						});
-					},
-				}
-			}
-		}
-		let tokens = tokens.into_values().collect();
-		Ok((self.accounts, tokens))
-	}
-}
-
-#[derive(Deserialize, Debug, Clone, Copy)]
-enum SignatureType {
-	#[serde(rename = "Sr25519")]
-	Sr25519,
-	#[serde(rename = "Ed25519")]
-	Ed25519,
-	#[serde(rename = "secp256k1")]
-	Secp256k1,
-	#[serde(rename = "*25519")]
-	Any25519,
-}
-
-#[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-struct AccountType {
-	prefix: u16,
-	network: String,
-	display_name: String,
-	/// If standard account is None then the network is reserved.
-	standard_account: Option<SignatureType>,
-	symbols: Vec<String>,
-	decimals: Vec<u8>,
-	website: Option<String>,
-}
-
-impl AccountType {
-	fn name(&self) -> String {
-		format!("{}Account", inflector::cases::pascalcase::to_pascal_case(&self.network))
-	}
-
-	fn tokens(&self) -> impl Iterator<Item = Ident> + '_ {
-		self.symbols.iter().map(|s| token_symbol_to_variant(s))
-	}
-
-	fn is_reserved(&self) -> bool {
-		self.standard_account.is_none()
-	}
-}
+use json::{parse_genesis_hash, NetworkStatus, Registry, SignatureType};
 
 fn token_symbol_to_variant(symbol: &str) -> Ident {
-	format_ident!("{}", inflector::cases::pascalcase::to_pascal_case(symbol))
+	format_ident!("{}", json::token_variant(symbol))
 }
 
-#[derive(Debug)]
-struct TokenType {
-	symbol: String,
-	decimals: u8,
-	networks: Vec<String>,
-}
-
-impl TokenType {
-	fn doc_string(&self) -> String {
-		format!("{} token used on {}", self.symbol, self.networks.join(", "))
+fn quote_option<T: ToTokens>(value: Option<T>) -> proc_macro2::TokenStream {
+	match value {
+		Some(value) => quote! { Some(#value) },
+		None => quote! { None },
 	}
 }
 
@@ -185,10 +55,9 @@ fn consecutive_runs(data: &[u16]) -> (Vec<u16>, Vec<u16>) {
 }
 
 fn create_ss58_registry(json: &str) -> Result<proc_macro2::TokenStream, String> {
-	let registry: Registry =
-		serde_json::from_str(json).map_err(|e| format!("json parsing error: {}", e))?;
-
-	let (mut accounts, tokens) = registry.validate()?;
+	let registry = Registry::from_json(json)?;
+	let tokens = registry.validate()?;
+	let mut accounts = registry.accounts;
 
 	// Sort by name so that we can later binary search by network
 	accounts.sort_by_key(|a| a.network.clone());
@@ -215,27 +84,157 @@ fn create_ss58_registry(json: &str) -> Result<proc_macro2::TokenStream, String>
 	let ident_to_tokens: Vec<_> = accounts
 		.iter()
 		.map(|r| {
-			let t = r.tokens();
+			let t = r
+				.symbols
+				.iter()
+				.filter(|s| tokens.iter().any(|t| t.symbol == **s))
+				.map(|s| token_symbol_to_variant(s));
 			quote! { #( TokenRegistry::#t ,)* }
 		})
 		.collect();
+	let ident_to_network_tokens: Vec<_> = accounts
+		.iter()
+		.zip(&identifier)
+		.map(|(r, ident)| {
+			let index = 0..r.symbols.len() as u8;
+			let (symbol, decimals) = (&r.symbols, &r.decimals);
+			quote! {
+				#( NetworkToken {
+					network: Ss58AddressFormatRegistry::#ident,
+					index: #index,
+					symbol: #symbol,
+					decimals: #decimals,
+				}, )*
+			}
+		})
+		.collect();
+
+	let display_name = accounts.iter().map(|r| &r.display_name);
+	let website = accounts.iter().map(|r| quote_option(r.website.as_ref()));
+
+	let genesis_hashes = accounts
+		.iter()
+		.map(|r| {
+			let hashes = r
+				.genesis_hashes
+				.iter()
+				.map(|h| parse_genesis_hash(h))
+				.collect::<Result<Vec<_>, _>>()?;
+			let hashes = hashes.iter().map(|h| quote! { [#(#h),*] });
+			Ok(quote! { #(#hashes,)* })
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+	let mut genesis_to_format = Vec::new();
+	for (account, ident) in accounts.iter().zip(&identifier) {
+		for hash in &account.genesis_hashes {
+			genesis_to_format.push((parse_genesis_hash(hash)?, ident));
+		}
+	}
+	genesis_to_format.sort_by_key(|(hash, _)| *hash);
+	let genesis_count = genesis_to_format.len();
+	let genesis_to_format = genesis_to_format
+		.iter()
+		.map(|(hash, ident)| quote! { ([#(#hash),*], Ss58AddressFormatRegistry::#ident) });
+
+	let variant_of = |network: &str| {
+		let account = accounts.iter().find(|a| a.network == network).expect("validated");
+		format_ident!("{}", account.name())
+	};
+	let relay_of = accounts.iter().map(|r| {
+		quote_option(r.relay_chain.as_deref().map(|relay_chain| {
+			let relay = variant_of(relay_chain);
+			quote! { Ss58AddressFormatRegistry::#relay }
+		}))
+	});
+	let para_id = accounts.iter().map(|r| quote_option(r.para_id));
+	let parachains_of = accounts.iter().map(|relay| {
+		let mut parachains: Vec<_> = accounts
+			.iter()
+			.filter(|a| a.relay_chain.as_ref() == Some(&relay.network))
+			.collect();
+		parachains.sort_by_key(|a| a.para_id);
+		let parachains = parachains.iter().map(|a| format_ident!("{}", a.name()));
+		quote! { #( Ss58AddressFormatRegistry::#parachains, )* }
+	});
+
+	let status = accounts.iter().map(|r| match r.effective_status() {
+		NetworkStatus::Mainnet => quote! { NetworkStatus::Mainnet },
+		NetworkStatus::Testnet => quote! { NetworkStatus::Testnet },
+		NetworkStatus::Deprecated => quote! { NetworkStatus::Deprecated },
+		NetworkStatus::Reserved => quote! { NetworkStatus::Reserved },
+	});
+
+	let standard_account = accounts.iter().map(|r| match r.standard_account {
+		Some(SignatureType::Sr25519) => quote! { Some(SignatureType::Sr25519) },
+		Some(SignatureType::Ed25519) => quote! { Some(SignatureType::Ed25519) },
+		Some(SignatureType::Secp256k1) => quote! { Some(SignatureType::Secp256k1) },
+		Some(SignatureType::Any25519) => quote! { Some(SignatureType::Any25519) },
+		None => quote! { None },
+	});
+
+	let explorer_arms = accounts.iter().zip(&identifier).filter_map(|(r, ident)| {
+		let explorer = r.explorer.as_ref()?;
+		let template = |t: &Option<String>| quote_option(t.as_ref());
+		let (address, block, extrinsic) =
+			(template(&explorer.address), template(&explorer.block), template(&explorer.extrinsic));
+		Some(quote! {
+			Ss58AddressFormatRegistry::#ident => match kind {
+				ExplorerKind::Address => #address,
+				ExplorerKind::Block => #block,
+				ExplorerKind::Extrinsic => #extrinsic,
+			},
+		})
+	});
 
 	let reserved_prefixes = accounts.iter().filter(|r| r.is_reserved()).map(|r| r.prefix);
 
 	let mut ordered_prefixes = accounts.iter().map(|i| i.prefix).collect::<Vec<_>>();
 	ordered_prefixes.sort_unstable();
 	let (prefix_starts, prefix_ends) = consecutive_runs(ordered_prefixes.as_slice());
+	let run_count = prefix_starts.len();
 
 	let token_defs: Vec<_> = tokens.iter().map(|t| token_symbol_to_variant(&t.symbol)).collect();
-	let token_names = tokens.iter().map(|t| t.symbol.to_owned());
+	let token_names: Vec<_> = tokens.iter().map(|t| t.symbol.to_owned()).collect();
+	let token_count = tokens.len();
 	let token_docs = tokens.iter().map(|t| t.doc_string());
 	let token_decimals = tokens.iter().map(|t| t.decimals);
+	let token_infos = tokens
+		.iter()
+		.map(|t| {
+			let symbol = &t.symbol;
+			let decimals = t.decimals;
+			let name = quote_option(t.metadata.name.as_ref());
+			let asset = match t.metadata.asset_id {
+				Some(id) => quote! { AssetKind::Asset(#id) },
+				None => quote! { AssetKind::Native },
+			};
+			let existential_deposit = quote_option(t.metadata.existential_deposit()?);
+			let slip44 = quote_option(t.metadata.slip44);
+			let coingecko_id = quote_option(t.metadata.coingecko_id.as_ref());
+			Ok(quote! {
+				TokenInfo {
+					symbol: #symbol,
+					name: #name,
+					decimals: #decimals,
+					asset: #asset,
+					existential_deposit: #existential_deposit,
+					slip44: #slip44,
+					coingecko_id: #coingecko_id,
+				}
+			})
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+	let token_denominations = tokens.iter().map(|t| {
+		let name = t.metadata.denominations.iter().map(|d| &d.name);
+		let decimals = t.metadata.denominations.iter().map(|d| d.decimals);
+		quote! { #( Denomination::Custom { name: #name, decimals: #decimals }, )* }
+	});
 
 	Ok(quote! {
 		/// A known address (sub)format/network ID for SS58.
 		#[non_exhaustive]
 		#[repr(u16)]
-		#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+		#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 		pub enum Ss58AddressFormatRegistry {
 			#(#[doc = #desc] #identifier = #prefix),*,
 		}
@@ -255,14 +254,97 @@ fn create_ss58_registry(json: &str) -> Result<proc_macro2::TokenStream, String>
 			#(#prefix_to_idx),*,
 		];
 
+		/// (Sorted) genesis hashes to address format of the chain
+		pub(crate) static GENESIS_TO_FORMAT: [([u8; 32], Ss58AddressFormatRegistry); #genesis_count] = [
+			#(#genesis_to_format),*
+		];
+
+		/// (Sorted) consecutive runs of the prefixes of all address formats
+		pub(crate) static ASSIGNED_PREFIX_RANGES: [core::ops::RangeInclusive<u16>; #run_count] = [
+			#(#prefix_starts..=#prefix_ends),*,
+		];
+
 		impl Ss58AddressFormatRegistry {
-			/// Tokens used on the given network.
-			pub fn tokens(&self) -> &'static[TokenRegistry] {
+			/// Tokens used on the given network that have a [`TokenRegistry`] variant. Scoped
+			/// symbols are left out, see [`network_tokens`](Self::network_tokens) for all of them.
+			pub fn tokens(&self) -> &'static [TokenRegistry] {
 				match self {
 					#(Ss58AddressFormatRegistry::#identifier => &[#ident_to_tokens],)*
 				}
 			}
 
+			/// Tokens used on the given network, ordered by Balances pallet instance.
+			pub fn network_tokens(&self) -> &'static [NetworkToken] {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => &[#ident_to_network_tokens],)*
+				}
+			}
+
+			/// Name of the network in a format friendly for display.
+			pub fn display_name(&self) -> &'static str {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #display_name,)*
+				}
+			}
+
+			/// Website or code repository of the network.
+			pub fn website(&self) -> Option<&'static str> {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #website,)*
+				}
+			}
+
+			/// Template of the block explorer link of the given kind, see
+			/// [`explorer_url`](Self::explorer_url) to render it.
+			pub fn explorer_template(&self, kind: ExplorerKind) -> Option<&'static str> {
+				match self {
+					#(#explorer_arms)*
+					_ => None,
+				}
+			}
+
+			/// Lifecycle status of the network.
+			pub fn status(&self) -> NetworkStatus {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #status,)*
+				}
+			}
+
+			/// Signing curve of the standard account of the network, `None` if reserved.
+			pub fn standard_account(&self) -> Option<SignatureType> {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #standard_account,)*
+				}
+			}
+
+			/// The relay chain of the network, if it is a parachain.
+			pub fn relay_of(&self) -> Option<Ss58AddressFormatRegistry> {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #relay_of,)*
+				}
+			}
+
+			/// Para id of the network on its relay chain, if it is a parachain.
+			pub fn para_id(&self) -> Option<u32> {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => #para_id,)*
+				}
+			}
+
+			/// The known parachains of this relay chain, ordered by para id.
+			pub fn parachains_of(&self) -> &'static [Ss58AddressFormatRegistry] {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => &[#parachains_of],)*
+				}
+			}
+
+			/// Genesis hashes of the chains known to use this network.
+			pub fn genesis_hashes(&self) -> &'static [[u8; 32]] {
+				match self {
+					#(Ss58AddressFormatRegistry::#identifier => &[#genesis_hashes],)*
+				}
+			}
+
 		}
 
 		impl Ss58AddressFormat {
@@ -279,6 +361,11 @@ fn create_ss58_registry(json: &str) -> Result<proc_macro2::TokenStream, String>
 			}
 		}
 
+		/// Symbols of all tokens (Sorted by symbol)
+		static ALL_TOKENS: [(&str, TokenRegistry); #token_count] = [
+			#((#token_names, TokenRegistry::#token_defs)),*
+		];
+
 		/// List of well-known tokens used on some network in the ecosystem.
 		#[non_exhaustive]
 		#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -294,12 +381,39 @@ fn create_ss58_registry(json: &str) -> Result<proc_macro2::TokenStream, String>
 				}
 			}
 		}
+
+		impl TokenRegistry {
+			/// Named denominations of the token declared in the registry in addition to the
+			/// standard ones (see [`Denomination`]).
+			pub fn denominations(&self) -> &'static [Denomination] {
+				match self {
+					#(TokenRegistry::#token_defs => &[#token_denominations],)*
+				}
+			}
+
+			/// The token with the given symbol, if it has a variant. Symbols are case sensitive.
+			pub fn from_symbol(symbol: &str) -> Option<TokenRegistry> {
+				ALL_TOKENS
+					.binary_search_by_key(&symbol, |(symbol, _)| symbol)
+					.ok()
+					.map(|lookup| ALL_TOKENS[lookup].1)
+			}
+
+			/// Metadata of the token declared in the registry.
+			pub fn info(&self) -> TokenInfo {
+				match self {
+					#(TokenRegistry::#token_defs => #token_infos,)*
+				}
+			}
+		}
 	})
 }
 
 fn main() {
 	println!("cargo:rerun-if-changed=build.rs");
 	println!("cargo:rerun-if-changed=ss58-registry.json");
+	println!("cargo:rerun-if-changed=src/json.rs");
+	println!("cargo:rerun-if-changed=src/hex_decode.rs");
 
 	let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR should exist");
 
@@ -14,6 +14,10 @@
 // limitations under the License.
 
 use super::*;
+use core::ops::RangeInclusive;
+
+/// Highest prefix that can be encoded in an SS58 address.
+pub(crate) const MAX_PREFIX: u16 = 0b0011_1111_1111_1111;
 
 /// A custom address format. See also [`Ss58AddressFormatRegistry`]
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -44,6 +48,47 @@ impl Ss58AddressFormat {
 	pub fn all() -> &'static [Ss58AddressFormatRegistry] {
 		&ALL_SS58_ADDRESS_FORMATS
 	}
+
+	/// All known address formats of networks with one of the given statuses, e.g. to only offer
+	/// live networks:
+	///
+	/// ```
+	/// # use ss58_registry::{NetworkStatus, Ss58AddressFormat};
+	/// let mut live = Ss58AddressFormat::all_with_status(&[NetworkStatus::Mainnet, NetworkStatus::Testnet]);
+	/// assert!(live.all(|network| !network.is_deprecated()));
+	/// ```
+	pub fn all_with_status(
+		statuses: &[NetworkStatus],
+	) -> impl Iterator<Item = Ss58AddressFormatRegistry> + '_ {
+		ALL_SS58_ADDRESS_FORMATS
+			.iter()
+			.copied()
+			.filter(|n| statuses.contains(&n.status()))
+	}
+
+	/// Prefixes encoded in a single byte of the address.
+	pub const ONE_BYTE_PREFIXES: RangeInclusive<u16> = 0..=63;
+
+	/// Prefixes encoded in two bytes of the address.
+	pub const TWO_BYTE_PREFIXES: RangeInclusive<u16> = 64..=MAX_PREFIX;
+
+	/// Consecutive runs of the prefixes of all known address formats (reserved ones included), in
+	/// ascending order. Prefixes outside of these are custom.
+	pub fn assigned_ranges() -> &'static [RangeInclusive<u16>] {
+		&ASSIGNED_PREFIX_RANGES
+	}
+
+	/// Prefixes within `range` that no known address format uses, in ascending order.
+	///
+	/// ```
+	/// # use ss58_registry::Ss58AddressFormat;
+	/// let mut free = Ss58AddressFormat::free_prefixes(Ss58AddressFormat::ONE_BYTE_PREFIXES);
+	/// assert!(free.all(|prefix| Ss58AddressFormat::custom(prefix).is_custom()));
+	/// ```
+	pub fn free_prefixes(range: RangeInclusive<u16>) -> impl Iterator<Item = u16> {
+		let end = (*range.end()).min(MAX_PREFIX);
+		(*range.start()..=end).filter(|prefix| Ss58AddressFormat::custom(*prefix).is_custom())
+	}
 }
 
 /// Display the name of the address format (not the description).
@@ -0,0 +1,510 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Command line tool to query the SS58 registry and work with addresses and token amounts.
+mod registry;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use ss58_registry::{
+	AssetKind, Caip10AccountId, DerivedAccount, ExplorerKind, NetworkToken, Ss58AddressFormat,
+	Ss58AddressFormatRegistry, SubstrateUri, Token, TokenAmount, H160,
+};
+use std::{fmt::Write, io::Write as _, ops::RangeInclusive};
+
+#[derive(Parser)]
+#[command(name = "ss58", version, about)]
+struct Cli {
+	/// Print JSON instead of human readable output.
+	#[arg(long, global = true)]
+	json: bool,
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// List all known networks.
+	List,
+	/// Show a network given by name, prefix or genesis hash.
+	Show {
+		/// Network name, prefix or `0x` prefixed genesis hash.
+		network: String,
+	},
+	/// Decode an SS58 address into its prefix and public key.
+	Decode {
+		/// The SS58 address, a CAIP-10 account id or a `substrate:` URI.
+		address: String,
+	},
+	/// Encode a public key as an SS58 address.
+	Encode {
+		/// Network name or prefix to encode for.
+		#[arg(long)]
+		network: String,
+		/// Hex encoded public key, with or without `0x`.
+		public_key: String,
+	},
+	/// Re-encode an SS58 address for another network.
+	Convert {
+		/// Network name or prefix to convert to.
+		#[arg(long)]
+		network: String,
+		/// The SS58 address.
+		address: String,
+	},
+	/// Derive the address of a multisig account.
+	Multisig {
+		/// Network name or prefix to encode the multisig address for.
+		#[arg(long)]
+		network: String,
+		/// Number of signatories needed to approve a call.
+		#[arg(long)]
+		threshold: u16,
+		/// SS58 addresses of the signatories, in any order and format.
+		#[arg(required = true)]
+		signatories: Vec<String>,
+	},
+	/// Derive the address of an account without a key pair.
+	Derive {
+		/// Network name or prefix to encode the derived address for.
+		#[arg(long)]
+		network: String,
+		#[command(subcommand)]
+		account: DeriveCommand,
+	},
+	/// Show the metadata of a token.
+	Token {
+		/// Token symbol or network name (for its first token).
+		token: String,
+		/// Network using the symbol, needed if networks use it with different decimals.
+		#[arg(long)]
+		network: Option<String>,
+	},
+	/// Format an amount of planck of a token, or parse an amount of whole units.
+	Amount {
+		/// Token symbol or network name (for its first token).
+		#[arg(long)]
+		token: String,
+		/// Network using the symbol, needed if networks use it with different decimals.
+		#[arg(long)]
+		network: Option<String>,
+		/// Interpret the value as whole units instead of planck.
+		#[arg(long)]
+		units: bool,
+		/// The amount.
+		value: String,
+	},
+	/// Show the runs of assigned prefixes, or of free ones.
+	Prefixes {
+		/// List the prefixes no network uses instead.
+		#[arg(long)]
+		free: bool,
+		/// Only consider prefixes encoded in this many bytes.
+		#[arg(long, value_enum)]
+		width: Option<PrefixWidth>,
+		/// Only consider prefixes for which addresses of 32-byte accounts can start with this.
+		#[arg(long)]
+		starting_with: Option<String>,
+	},
+	/// Work with the registry file itself.
+	Registry {
+		#[command(subcommand)]
+		command: registry::RegistryCommand,
+	},
+}
+
+#[derive(Subcommand)]
+enum DeriveCommand {
+	/// The account of a pallet.
+	Pallet {
+		/// The 8 character `PalletId`, like `py/trsry`.
+		id: String,
+	},
+	/// The sovereign account of a parachain on its relay chain.
+	Para {
+		/// Para id of the parachain.
+		id: u32,
+	},
+	/// The sovereign account of a parachain on its sibling parachains.
+	Sibling {
+		/// Para id of the parachain.
+		id: u32,
+	},
+	/// The account `utility.as_derivative` dispatches from.
+	Derivative {
+		/// SS58 address of the deriving account.
+		address: String,
+		/// Index of the derivative account.
+		#[arg(long, default_value_t = 0)]
+		index: u16,
+	},
+}
+
+/// Number of bytes used to encode a prefix in an address.
+#[derive(Clone, Copy, ValueEnum)]
+enum PrefixWidth {
+	/// Prefixes 0 to 63.
+	One,
+	/// Prefixes 64 to 16383.
+	Two,
+}
+
+/// Result of a command in both human readable and JSON form.
+struct Output {
+	text: String,
+	json: Value,
+}
+
+/// Parses a network name or a numeric prefix.
+fn parse_format(network: &str) -> Result<Ss58AddressFormat, String> {
+	if network.is_empty() || !network.bytes().all(|b| b.is_ascii_digit()) {
+		return Ss58AddressFormat::try_from(network)
+			.map_err(|_| format!("unknown network `{}`", network))
+	}
+	let max = *Ss58AddressFormat::TWO_BYTE_PREFIXES.end();
+	let invalid = || format!("invalid prefix `{}`, SS58 prefixes go up to {}", network, max);
+	let format = Ss58AddressFormat::custom(network.parse().map_err(|_| invalid())?);
+	// Registered networks above the range can still be looked up by prefix.
+	if format.prefix() > max && format.is_custom() {
+		return Err(invalid())
+	}
+	Ok(format)
+}
+
+fn parse_known_format(network: &str) -> Result<Ss58AddressFormatRegistry, String> {
+	if let Some(hash) = network.strip_prefix("0x") {
+		let mut genesis = [0; 32];
+		hex::decode_to_slice(hash, &mut genesis)
+			.map_err(|e| format!("invalid genesis hash `{}`: {}", network, e))?;
+		return Ss58AddressFormatRegistry::lookup_by_genesis(&genesis)
+			.ok_or_else(|| format!("no network is registered for genesis hash `{}`", network))
+	}
+	Ss58AddressFormatRegistry::try_from(parse_format(network)?)
+		.map_err(|_| format!("no network is registered for `{}`", network))
+}
+
+/// Finds a token by its symbol, on the given network if any, or the network using it.
+fn parse_token(token: &str, network: Option<&str>) -> Result<NetworkToken, String> {
+	if let Some(network) = network {
+		let network = parse_known_format(network)?;
+		return network
+			.network_tokens()
+			.iter()
+			.find(|t| t.symbol.eq_ignore_ascii_case(token))
+			.copied()
+			.ok_or_else(|| format!("network `{}` has no token `{}`", network, token))
+	}
+	let by_symbol: Vec<_> = Ss58AddressFormat::all()
+		.iter()
+		.flat_map(|network| network.network_tokens())
+		.filter(|t| t.symbol.eq_ignore_ascii_case(token))
+		.collect();
+	if let Some(first) = by_symbol.first() {
+		if by_symbol.iter().any(|t| t.decimals != first.decimals) {
+			return Err(format!(
+				"networks use `{}` with different decimals, select one with --network",
+				token
+			))
+		}
+		return Ok(**first)
+	}
+	let network = Ss58AddressFormatRegistry::try_from(token)
+		.map_err(|_| format!("unknown token or network `{}`", token))?;
+	network
+		.native_token()
+		.ok_or_else(|| format!("network `{}` has no tokens", token))
+}
+
+fn network_json(network: Ss58AddressFormatRegistry) -> Value {
+	let format = Ss58AddressFormat::from(network);
+	let tokens = network.network_tokens();
+	json!({
+		"prefix": format.prefix(),
+		"network": network.to_string(),
+		"displayName": network.display_name(),
+		"symbols": tokens.iter().map(|t| t.symbol).collect::<Vec<_>>(),
+		"decimals": tokens.iter().map(|t| t.decimals).collect::<Vec<_>>(),
+		"website": network.website(),
+		"genesisHashes": network
+			.genesis_hashes()
+			.iter()
+			.map(|hash| format!("0x{}", hex::encode(hash)))
+			.collect::<Vec<_>>(),
+		"relayChain": network.relay_of().map(|relay| relay.to_string()),
+		"paraId": network.para_id(),
+		"parachains": network.parachains_of().iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+		"status": format!("{:?}", network.status()).to_lowercase(),
+		"explorer": network.explorer_template(ExplorerKind::Address),
+		"reserved": format.is_reserved(),
+		"leadingChars": format.leading_chars().into_iter().collect::<String>(),
+	})
+}
+
+fn address_json(format: Ss58AddressFormat, public_key: &[u8], address: &str) -> Value {
+	let network = Ss58AddressFormatRegistry::try_from(format).ok();
+	json!({
+		"address": address,
+		"prefix": format.prefix(),
+		"network": network.map(|n| n.to_string()),
+		"publicKey": format!("0x{}", hex::encode(public_key)),
+		"explorer": network.and_then(|n| n.explorer_url(ExplorerKind::Address, address)),
+		"caip10": network
+			.and_then(|n| n.caip2_chain_ids().first().copied())
+			.and_then(|chain| Caip10AccountId::new(chain, address).ok())
+			.map(|account| account.to_string()),
+		"uri": network
+			.and_then(|n| n.genesis_hashes().first())
+			.and_then(|genesis| SubstrateUri::new(address, *genesis).ok())
+			.map(|uri| uri.to_string()),
+		"h160": network
+			.filter(|n| n.is_ethereum())
+			.and_then(|_| H160::from_public_key(public_key).ok())
+			.map(|account| account.to_string()),
+	})
+}
+
+fn address_text(format: Ss58AddressFormat, public_key: &[u8], address: &str) -> String {
+	format!(
+		"address:    {}\nprefix:     {}\nnetwork:    {}\npublic key: 0x{}",
+		address,
+		format.prefix(),
+		format,
+		hex::encode(public_key)
+	)
+}
+
+fn amount_output(amount: TokenAmount) -> Output {
+	Output {
+		text: amount.to_string(),
+		json: json!({
+			"symbol": amount.token.name,
+			"decimals": amount.token.decimals,
+			"planck": amount.amount.to_string(),
+			"units": amount.to_decimal_string(),
+		}),
+	}
+}
+
+/// Collapses ascending prefixes into runs of consecutive ones.
+fn prefix_runs(prefixes: impl Iterator<Item = u16>) -> Vec<RangeInclusive<u16>> {
+	let mut runs: Vec<RangeInclusive<u16>> = Vec::new();
+	for prefix in prefixes {
+		match runs.last_mut() {
+			Some(run) if *run.end() + 1 == prefix => *run = *run.start()..=prefix,
+			_ => runs.push(prefix..=prefix),
+		}
+	}
+	runs
+}
+
+fn prefix_runs_output(runs: &[RangeInclusive<u16>]) -> Output {
+	let mut text = String::new();
+	for run in runs {
+		let count = run.end() - run.start() + 1;
+		let run = if count == 1 {
+			run.start().to_string()
+		} else {
+			format!("{}-{}", run.start(), run.end())
+		};
+		let _ = writeln!(text, "{:>11}  {}", run, count);
+	}
+	let total: u32 = runs.iter().map(|run| u32::from(run.end() - run.start()) + 1).sum();
+	let _ = write!(text, "{} prefixes in {} runs", total, runs.len());
+	Output {
+		text,
+		json: runs
+			.iter()
+			.map(|run| json!({ "start": run.start(), "end": run.end() }))
+			.collect(),
+	}
+}
+
+fn run(command: Command) -> Result<Output, String> {
+	match command {
+		Command::List => {
+			let mut networks: Vec<_> = Ss58AddressFormat::all().to_vec();
+			networks.sort_by_key(|n| Ss58AddressFormat::from(*n).prefix());
+			let mut text = String::new();
+			for network in &networks {
+				let prefix = Ss58AddressFormat::from(*network).prefix();
+				let name = network.to_string();
+				let _ = writeln!(text, "{:>5}  {:<24} {}", prefix, name, network.display_name());
+			}
+			Ok(Output {
+				text: text.trim_end().to_owned(),
+				json: networks.into_iter().map(network_json).collect(),
+			})
+		},
+		Command::Show { network } => {
+			let network = parse_known_format(&network)?;
+			let json = network_json(network);
+			let mut text = String::new();
+			for (key, value) in json.as_object().expect("network is an object") {
+				let value = match value {
+					Value::String(value) => value.clone(),
+					value => value.to_string(),
+				};
+				let _ = writeln!(text, "{:<14} {}", format!("{}:", key), value);
+			}
+			Ok(Output { text: text.trim_end().to_owned(), json })
+		},
+		Command::Decode { address } => {
+			let address = if !address.contains(':') {
+				address
+			} else if address.starts_with("substrate:") {
+				let uri: SubstrateUri =
+					address.parse().map_err(|e| format!("{}: {}", address, e))?;
+				uri.address().to_owned()
+			} else {
+				let account: Caip10AccountId =
+					address.parse().map_err(|e| format!("{}: {}", address, e))?;
+				account.address().to_owned()
+			};
+			let (format, public_key) =
+				Ss58AddressFormat::decode(&address).map_err(|e| format!("{}: {}", address, e))?;
+			Ok(Output {
+				text: address_text(format, &public_key, &address),
+				json: address_json(format, &public_key, &address),
+			})
+		},
+		Command::Encode { network, public_key } => {
+			let format = parse_format(&network)?;
+			let public_key = hex::decode(public_key.trim_start_matches("0x"))
+				.map_err(|e| format!("invalid public key: {}", e))?;
+			let address = format.encode(&public_key).map_err(|e| e.to_string())?;
+			Ok(Output { text: address.clone(), json: address_json(format, &public_key, &address) })
+		},
+		Command::Convert { network, address } => {
+			let format = parse_format(&network)?;
+			let (_, public_key) =
+				Ss58AddressFormat::decode(&address).map_err(|e| format!("{}: {}", address, e))?;
+			let address = format.encode(&public_key).map_err(|e| e.to_string())?;
+			Ok(Output { text: address.clone(), json: address_json(format, &public_key, &address) })
+		},
+		Command::Multisig { network, threshold, signatories } => {
+			let format = parse_format(&network)?;
+			let signatories: Vec<_> = signatories.iter().map(String::as_str).collect();
+			let address =
+				format.multisig_address(&signatories, threshold).map_err(|e| e.to_string())?;
+			let (_, public_key) = Ss58AddressFormat::decode(&address).expect("just encoded");
+			Ok(Output { text: address.clone(), json: address_json(format, &public_key, &address) })
+		},
+		Command::Derive { network, account } => {
+			let format = parse_format(&network)?;
+			let account = match account {
+				DeriveCommand::Pallet { id } => DerivedAccount::Pallet(
+					id.as_bytes()
+						.try_into()
+						.map_err(|_| format!("pallet id `{}` is not 8 bytes long", id))?,
+				),
+				DeriveCommand::Para { id } => DerivedAccount::Parachain(id),
+				DeriveCommand::Sibling { id } => DerivedAccount::Sibling(id),
+				DeriveCommand::Derivative { address, index } => {
+					let (_, who) = Ss58AddressFormat::decode(&address)
+						.map_err(|e| format!("{}: {}", address, e))?;
+					let who = who
+						.try_into()
+						.map_err(|_| format!("{}: not a 32 byte account", address))?;
+					DerivedAccount::Derivative { who, index }
+				},
+			};
+			let address = account.address(format).map_err(|e| e.to_string())?;
+			Ok(Output {
+				text: address.clone(),
+				json: address_json(format, &account.account(), &address),
+			})
+		},
+		Command::Token { token, network } => {
+			let info = parse_token(&token, network.as_deref())?
+				.token()
+				.ok_or_else(|| format!("networks disagree on the decimals of `{}`", token))?
+				.info();
+			let json = json!({
+				"symbol": info.symbol,
+				"name": info.name,
+				"decimals": info.decimals,
+				"assetId": match info.asset {
+					AssetKind::Native => None,
+					AssetKind::Asset(id) => Some(id),
+				},
+				"existentialDeposit": info.existential_deposit.map(|deposit| deposit.to_string()),
+				"slip44": info.slip44,
+				"coingeckoId": info.coingecko_id,
+			});
+			let mut text = String::new();
+			for (key, value) in json.as_object().expect("token is an object") {
+				let value = match value {
+					Value::String(value) => value.clone(),
+					value => value.to_string(),
+				};
+				let _ = writeln!(text, "{:<19} {}", format!("{}:", key), value);
+			}
+			Ok(Output { text: text.trim_end().to_owned(), json })
+		},
+		Command::Amount { token, network, units, value } => {
+			let token = Token::from(parse_token(&token, network.as_deref())?);
+			let amount = if units {
+				token.parse_amount(&value).map_err(|e| format!("{}: {}", value, e))?
+			} else {
+				let planck = value.parse().map_err(|e| format!("{}: {}", value, e))?;
+				token.amount(planck)
+			};
+			Ok(amount_output(amount))
+		},
+		Command::Prefixes { free, width, starting_with } => {
+			let range = match width {
+				Some(PrefixWidth::One) => Ss58AddressFormat::ONE_BYTE_PREFIXES,
+				Some(PrefixWidth::Two) => Ss58AddressFormat::TWO_BYTE_PREFIXES,
+				None =>
+					*Ss58AddressFormat::ONE_BYTE_PREFIXES.start()..=
+						*Ss58AddressFormat::TWO_BYTE_PREFIXES.end(),
+			};
+			let runs = match (free, starting_with) {
+				(true, Some(start)) =>
+					prefix_runs(Ss58AddressFormat::free_prefixes_starting_with(range, &start)),
+				(false, Some(start)) => prefix_runs(
+					Ss58AddressFormat::prefixes_starting_with(range, &start)
+						.filter(|prefix| !Ss58AddressFormat::custom(*prefix).is_custom()),
+				),
+				(true, None) => prefix_runs(Ss58AddressFormat::free_prefixes(range)),
+				(false, None) => Ss58AddressFormat::assigned_ranges()
+					.iter()
+					.filter(|run| run.start() <= range.end() && run.end() >= range.start())
+					.map(|run| *run.start().max(range.start())..=*run.end().min(range.end()))
+					.collect(),
+			};
+			Ok(prefix_runs_output(&runs))
+		},
+		Command::Registry { command } => registry::run(command),
+	}
+}
+
+fn main() {
+	let cli = Cli::parse();
+	// Output errors, e.g. a closed pipe, are not worth a panic.
+	let mut stdout = std::io::stdout();
+	match run(cli.command) {
+		Ok(output) if cli.json => {
+			let _ = writeln!(stdout, "{:#}", output.json);
+		},
+		Ok(output) => {
+			let _ = writeln!(stdout, "{}", output.text);
+		},
+		Err(msg) => {
+			eprintln!("error: {}", msg);
+			std::process::exit(1);
+		},
+	}
+}
@@ -0,0 +1,309 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Subcommands working on the `ss58-registry.json` file.
+use crate::Output;
+use clap::{Args, Subcommand};
+use serde_json::{json, Value};
+use ss58_registry::{
+	diff,
+	json::{self, AccountType, ExplorerTemplates, NetworkStatus, Registry, SignatureType},
+};
+use std::{
+	fs,
+	io::{BufRead, IsTerminal, Write},
+	path::{Path, PathBuf},
+};
+
+#[derive(Subcommand)]
+pub enum RegistryCommand {
+	/// Add a new network to the registry file.
+	///
+	/// Missing values are asked for when run in a terminal.
+	Propose(Box<Propose>),
+	/// Rewrite the registry file in canonical form.
+	Fmt {
+		/// Path of the registry file.
+		#[arg(long, default_value = "ss58-registry.json")]
+		file: PathBuf,
+		/// Fail instead of rewriting the file if it is not in canonical form.
+		#[arg(long)]
+		check: bool,
+	},
+	/// Show the changes between two versions of the registry file as Markdown.
+	Diff {
+		/// Path of the previous registry file.
+		old: PathBuf,
+		/// Path of the new registry file.
+		#[arg(default_value = "ss58-registry.json")]
+		new: PathBuf,
+	},
+	/// Check the crate version was bumped enough for the changes since a baseline.
+	///
+	/// Renaming or removing networks and tokens changes the generated enums and so requires a
+	/// major version bump; additions require a minor one and other changes a patch one.
+	Semver {
+		/// Path of the registry file of the baseline release.
+		#[arg(long)]
+		baseline: PathBuf,
+		/// Crate version of the baseline release.
+		#[arg(long)]
+		baseline_version: diff::Version,
+		/// Path of the current registry file.
+		#[arg(long, default_value = "ss58-registry.json")]
+		file: PathBuf,
+		/// Path of the `Cargo.toml` with the current version.
+		#[arg(long, default_value = "Cargo.toml")]
+		manifest: PathBuf,
+	},
+}
+
+#[derive(Args)]
+pub struct Propose {
+	/// Path of the registry file.
+	#[arg(long, default_value = "ss58-registry.json")]
+	file: PathBuf,
+	/// Prefix of the network. Defaults to the lowest free one.
+	#[arg(long)]
+	prefix: Option<u16>,
+	/// Unique identifier of the network, without spaces.
+	#[arg(long)]
+	network: Option<String>,
+	/// Name of the network in a format friendly for display.
+	#[arg(long)]
+	display_name: Option<String>,
+	/// Token symbol. Repeat for each instance of the Balances pallet.
+	#[arg(long = "symbol")]
+	symbols: Vec<String>,
+	/// Decimals of the token. Repeat for each symbol.
+	#[arg(long = "decimals")]
+	decimals: Vec<u8>,
+	/// Signing curve of the standard account: `Sr25519`, `Ed25519`, `secp256k1` or `*25519`.
+	#[arg(long)]
+	standard_account: Option<String>,
+	/// Website or code repository of the network.
+	#[arg(long)]
+	website: Option<String>,
+	/// Hex encoded genesis hash of a chain using the network. Repeat for each chain.
+	#[arg(long = "genesis-hash")]
+	genesis_hashes: Vec<String>,
+	/// Network of the relay chain, if the network is a parachain.
+	#[arg(long, requires = "para_id")]
+	relay_chain: Option<String>,
+	/// Para id of the network on its relay chain.
+	#[arg(long, requires = "relay_chain")]
+	para_id: Option<u32>,
+	/// Status of the network: `mainnet` (the default), `testnet` or `deprecated`.
+	#[arg(long)]
+	status: Option<String>,
+	/// Block explorer link to an account, containing `{address}`.
+	#[arg(long)]
+	explorer_address: Option<String>,
+	/// Block explorer link to a block, containing `{block}`.
+	#[arg(long)]
+	explorer_block: Option<String>,
+	/// Block explorer link to an extrinsic, containing `{extrinsic}`.
+	#[arg(long)]
+	explorer_extrinsic: Option<String>,
+	/// Validate and print the entry without writing the file.
+	#[arg(long)]
+	dry_run: bool,
+}
+
+pub fn run(command: RegistryCommand) -> Result<Output, String> {
+	match command {
+		RegistryCommand::Propose(propose) => propose.run(),
+		RegistryCommand::Fmt { file, check } => fmt(&file, check),
+		RegistryCommand::Diff { old, new } => {
+			let diff = diff::diff(&read_registry(&old)?, &read_registry(&new)?);
+			let changes: Vec<_> = diff
+				.changes
+				.iter()
+				.map(|c| json!({ "breaking": c.is_breaking(), "description": c.to_string() }))
+				.collect();
+			let text = if diff.changes.is_empty() {
+				"no changes".to_owned()
+			} else {
+				diff.to_markdown().trim_end().to_owned()
+			};
+			Ok(Output { text, json: json!({ "breaking": diff.is_breaking(), "changes": changes }) })
+		},
+		RegistryCommand::Semver { baseline, baseline_version, file, manifest } => {
+			let diff = diff::diff(&read_registry(&baseline)?, &read_registry(&file)?);
+			let version = fs::read_to_string(&manifest)
+				.map_err(|e| e.to_string())
+				.and_then(|manifest| diff::Version::from_manifest(&manifest))
+				.map_err(|e| format!("{}: {}", manifest.display(), e))?;
+			diff::check_version_bump(&diff, &baseline_version, &version)?;
+			let bump = format!("{:?}", diff.required_bump()).to_lowercase();
+			Ok(Output {
+				text: format!(
+					"version {} is a valid successor of {} (required bump: {})",
+					version, baseline_version, bump
+				),
+				json: json!({
+					"baselineVersion": baseline_version.to_string(),
+					"version": version.to_string(),
+					"requiredBump": bump,
+				}),
+			})
+		},
+	}
+}
+
+fn fmt(path: &Path, check: bool) -> Result<Output, String> {
+	let json = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+	let formatted = json::format(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+	let canonical = json == formatted;
+	if check && !canonical {
+		return Err(format!(
+			"{} is not in canonical form, run `ss58 registry fmt` to fix it",
+			path.display()
+		))
+	}
+	if !canonical {
+		fs::write(path, formatted).map_err(|e| format!("{}: {}", path.display(), e))?;
+	}
+	let text = if canonical { "already canonical" } else { "reformatted" };
+	Ok(Output {
+		text: format!("{}: {}", path.display(), text),
+		json: json!({ "file": path.display().to_string(), "changed": !canonical }),
+	})
+}
+
+pub fn read_registry(path: &Path) -> Result<Registry, String> {
+	let json = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+	Registry::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Asks for a value on the terminal, `None` if left empty.
+fn prompt(question: &str) -> Result<Option<String>, String> {
+	eprint!("{}: ", question);
+	std::io::stderr().flush().map_err(|e| e.to_string())?;
+	let mut answer = String::new();
+	std::io::stdin().lock().read_line(&mut answer).map_err(|e| e.to_string())?;
+	let answer = answer.trim();
+	Ok((!answer.is_empty()).then(|| answer.to_owned()))
+}
+
+fn parse_signature_type(value: &str) -> Result<SignatureType, String> {
+	serde_json::from_value(Value::String(value.to_owned())).map_err(|_| {
+		format!(
+			"invalid standard account `{}`, expected `Sr25519`, `Ed25519`, `secp256k1` or `*25519`",
+			value
+		)
+	})
+}
+
+fn parse_status(value: &str) -> Result<NetworkStatus, String> {
+	serde_json::from_value(Value::String(value.to_owned())).map_err(|_| {
+		format!(
+			"invalid status `{}`, expected `mainnet`, `testnet`, `deprecated` or `reserved`",
+			value
+		)
+	})
+}
+
+impl Propose {
+	/// Fills in missing values interactively.
+	fn ask(&mut self, suggested_prefix: u16) -> Result<(), String> {
+		if self.prefix.is_none() {
+			let question = format!("prefix [{}]", suggested_prefix);
+			if let Some(prefix) = prompt(&question)? {
+				self.prefix = Some(prefix.parse().map_err(|e| format!("{}: {}", prefix, e))?);
+			}
+		}
+		if self.network.is_none() {
+			self.network = prompt("network (unique, no spaces)")?;
+		}
+		if self.display_name.is_none() {
+			self.display_name = prompt("display name")?;
+		}
+		if self.symbols.is_empty() {
+			if let Some(symbols) = prompt("symbols (comma separated, empty for none)")? {
+				self.symbols = symbols.split(',').map(|s| s.trim().to_owned()).collect();
+			}
+		}
+		if self.decimals.is_empty() && !self.symbols.is_empty() {
+			if let Some(decimals) = prompt("decimals for each symbol (comma separated)")? {
+				self.decimals = decimals
+					.split(',')
+					.map(|d| d.trim().parse().map_err(|e| format!("{}: {}", d, e)))
+					.collect::<Result<_, _>>()?;
+			}
+		}
+		if self.standard_account.is_none() {
+			self.standard_account =
+				prompt("standard account (Sr25519, Ed25519, secp256k1, *25519)")?;
+		}
+		if self.website.is_none() {
+			self.website = prompt("website (optional)")?;
+		}
+		Ok(())
+	}
+
+	fn run(mut self) -> Result<Output, String> {
+		let mut registry = read_registry(&self.file)?;
+		let suggested_prefix =
+			registry.lowest_free_prefix().ok_or("no free prefix left in the registry")?;
+
+		let complete = self.network.is_some() &&
+			self.display_name.is_some() &&
+			self.standard_account.is_some();
+		if !complete && std::io::stdin().is_terminal() {
+			self.ask(suggested_prefix)?;
+		}
+
+		let standard_account = self.standard_account.ok_or("missing --standard-account")?;
+		let account = AccountType {
+			prefix: self.prefix.unwrap_or(suggested_prefix),
+			network: self.network.ok_or("missing --network")?,
+			display_name: self.display_name.ok_or("missing --display-name")?,
+			symbols: self.symbols,
+			decimals: self.decimals,
+			standard_account: Some(parse_signature_type(&standard_account)?),
+			website: self.website,
+			genesis_hashes: self.genesis_hashes,
+			relay_chain: self.relay_chain,
+			para_id: self.para_id,
+			status: self.status.as_deref().map(parse_status).transpose()?,
+			explorer: Some(ExplorerTemplates {
+				address: self.explorer_address,
+				block: self.explorer_block,
+				extrinsic: self.explorer_extrinsic,
+			})
+			.filter(|explorer| *explorer != ExplorerTemplates::default()),
+		};
+		registry.accounts.push(account.clone());
+		registry.validate()?;
+		registry.sort();
+
+		let entry = serde_json::to_value(&account).expect("entry is representable as JSON");
+		let text = if self.dry_run {
+			format!("{:#}", entry)
+		} else {
+			fs::write(&self.file, registry.to_json())
+				.map_err(|e| format!("{}: {}", self.file.display(), e))?;
+			format!(
+				"added `{}` with prefix {} to {}\n{:#}",
+				account.network,
+				account.prefix,
+				self.file.display(),
+				entry
+			)
+		};
+		Ok(Output { text, json: json!({ "written": !self.dry_run, "entry": entry }) })
+	}
+}
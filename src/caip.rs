@@ -0,0 +1,153 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [CAIP-2](https://chainagnostic.org/CAIPs/caip-2) chain ids and
+//! [CAIP-10](https://chainagnostic.org/CAIPs/caip-10) account ids in the `polkadot` namespace,
+//! which identifies chains by the first 16 bytes of their genesis hash.
+
+use super::*;
+use crate::{hex_decode::decode_hex, registry::GENESIS_TO_FORMAT};
+use core::{fmt, str::FromStr};
+
+const NAMESPACE: &str = "polkadot";
+
+/// A CAIP-2 chain id like `polkadot:91b171bb158e2d3848fa23a9f1c25182`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Caip2ChainId {
+	reference: [u8; 16],
+}
+
+impl Caip2ChainId {
+	/// The chain id of the chain with the given genesis hash.
+	pub fn from_genesis(genesis: &[u8; 32]) -> Self {
+		let mut reference = [0; 16];
+		reference.copy_from_slice(&genesis[..16]);
+		Caip2ChainId { reference }
+	}
+
+	/// The first 16 bytes of the genesis hash of the chain.
+	pub fn reference(&self) -> [u8; 16] {
+		self.reference
+	}
+
+	/// The network of the chain, if its genesis hash is in the registry.
+	pub fn network(&self) -> Option<Ss58AddressFormatRegistry> {
+		GENESIS_TO_FORMAT
+			.binary_search_by(|(genesis, _)| genesis[..16].cmp(&self.reference))
+			.ok()
+			.map(|lookup| GENESIS_TO_FORMAT[lookup].1)
+	}
+
+	/// The CAIP-10 id of the account on this chain, encoded in the address format of its
+	/// network.
+	///
+	/// ```
+	/// # use ss58_registry::{Caip10AccountId, Ss58AddressFormat, Ss58AddressFormatRegistry};
+	/// let polkadot = Ss58AddressFormatRegistry::PolkadotAccount.caip2_chain_ids()[0];
+	/// let (_, alice) =
+	///     Ss58AddressFormat::decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+	/// let account = polkadot.account(&alice).unwrap();
+	/// assert_eq!(
+	///     account.to_string(),
+	///     "polkadot:91b171bb158e2d3848fa23a9f1c25182:15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+	/// );
+	/// assert_eq!(account.to_string().parse::<Caip10AccountId>(), Ok(account));
+	/// ```
+	pub fn account(&self, account: &[u8]) -> Result<Caip10AccountId, CaipError> {
+		let network = self.network().ok_or(CaipError::UnknownChain)?;
+		let address = Ss58AddressFormat::from(network).encode(account)?;
+		Ok(Caip10AccountId { chain: *self, address })
+	}
+}
+
+impl fmt::Display for Caip2ChainId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}:", NAMESPACE)?;
+		self.reference.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+	}
+}
+
+impl FromStr for Caip2ChainId {
+	type Err = CaipError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (namespace, reference) = s.split_once(':').ok_or(CaipError::InvalidNamespace)?;
+		if namespace != NAMESPACE {
+			return Err(CaipError::InvalidNamespace)
+		}
+		let reference = decode_hex(reference)
+			.filter(|_| !reference.bytes().any(|d| d.is_ascii_uppercase()))
+			.ok_or(CaipError::InvalidReference)?;
+		Ok(Caip2ChainId { reference })
+	}
+}
+
+/// A CAIP-10 account id: a [`Caip2ChainId`] followed by an SS58 address.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Caip10AccountId {
+	chain: Caip2ChainId,
+	address: String,
+}
+
+impl Caip10AccountId {
+	/// Combines the chain and the SS58 address, checking the address is valid and, if the chain
+	/// is in the registry, uses the format of its network.
+	pub fn new(chain: Caip2ChainId, address: &str) -> Result<Self, CaipError> {
+		let (format, _) = Ss58AddressFormat::decode(address)?;
+		match chain.network() {
+			Some(network) if Ss58AddressFormat::from(network) != format =>
+				Err(CaipError::FormatMismatch),
+			_ => Ok(Caip10AccountId { chain, address: address.to_owned() }),
+		}
+	}
+
+	/// The chain of the account.
+	pub fn chain(&self) -> Caip2ChainId {
+		self.chain
+	}
+
+	/// The SS58 address of the account.
+	pub fn address(&self) -> &str {
+		&self.address
+	}
+
+	/// The account data encoded in the address.
+	pub fn account(&self) -> Vec<u8> {
+		let (_, account) = Ss58AddressFormat::decode(&self.address).expect("checked on creation");
+		account
+	}
+}
+
+impl fmt::Display for Caip10AccountId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}:{}", self.chain, self.address)
+	}
+}
+
+impl FromStr for Caip10AccountId {
+	type Err = CaipError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (chain, address) = s.rsplit_once(':').ok_or(CaipError::InvalidNamespace)?;
+		Caip10AccountId::new(chain.parse()?, address)
+	}
+}
+
+impl Ss58AddressFormatRegistry {
+	/// The CAIP-2 ids of the chains known to use this network, one per genesis hash.
+	pub fn caip2_chain_ids(&self) -> Vec<Caip2ChainId> {
+		self.genesis_hashes().iter().map(Caip2ChainId::from_genesis).collect()
+	}
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Well-known accounts derived from other data rather than from a key pair.
+
+use super::*;
+use crate::{multisig::UTILITY_PREFIX, ss58::blake2_256};
+
+/// An account without a key pair, derived from a pallet id, a para id or another account.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DerivedAccount {
+	/// The account of a pallet, given by its `PalletId` like `py/trsry` for the treasury.
+	Pallet([u8; 8]),
+	/// The sovereign account of a parachain on its relay chain.
+	Parachain(u32),
+	/// The sovereign account of a parachain on its sibling parachains.
+	Sibling(u32),
+	/// The account the Utility pallet's `as_derivative` call dispatches from.
+	Derivative {
+		/// The account deriving the new one.
+		who: [u8; 32],
+		/// Index of the derivative account.
+		index: u16,
+	},
+}
+
+impl DerivedAccount {
+	/// The 32 byte account id.
+	pub fn account(&self) -> [u8; 32] {
+		let truncated = |tag: &[u8], data: &[u8]| {
+			let mut account = [0; 32];
+			account[..tag.len()].copy_from_slice(tag);
+			account[tag.len()..tag.len() + data.len()].copy_from_slice(data);
+			account
+		};
+		match self {
+			DerivedAccount::Pallet(id) => truncated(b"modl", id),
+			DerivedAccount::Parachain(para_id) => truncated(b"para", &para_id.to_le_bytes()),
+			DerivedAccount::Sibling(para_id) => truncated(b"sibl", &para_id.to_le_bytes()),
+			DerivedAccount::Derivative { who, index } =>
+				blake2_256(&[UTILITY_PREFIX, who, &index.to_le_bytes()]),
+		}
+	}
+
+	/// The SS58 address of the account in the given format.
+	///
+	/// ```
+	/// # use ss58_registry::{DerivedAccount, Ss58AddressFormatRegistry};
+	/// let treasury = DerivedAccount::Pallet(*b"py/trsry");
+	/// assert_eq!(
+	///     treasury.address(Ss58AddressFormatRegistry::PolkadotAccount.into()).unwrap(),
+	///     "13UVJyLnbVp9RBZYFwFGyDvVd1y27Tt8tkntv6Q7JVPhFsTB",
+	/// );
+	/// ```
+	pub fn address(&self, format: Ss58AddressFormat) -> Result<String, AddressError> {
+		format.encode(&self.account())
+	}
+}
+
+impl Ss58AddressFormatRegistry {
+	/// The sovereign account of the parachain on its relay chain, as an address of the relay
+	/// chain.
+	///
+	/// ```
+	/// # use ss58_registry::Ss58AddressFormatRegistry;
+	/// assert_eq!(
+	///     Ss58AddressFormatRegistry::AcalaAccount.sovereign_address().as_deref(),
+	///     Some("13YMK2eYoAvStnzReuxBjMrAvPXmmdsURwZvc62PrdXimbNy"),
+	/// );
+	/// ```
+	pub fn sovereign_address(&self) -> Option<String> {
+		let relay = Ss58AddressFormat::from(self.relay_of()?);
+		DerivedAccount::Parachain(self.para_id()?).address(relay).ok()
+	}
+}
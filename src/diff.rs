@@ -0,0 +1,740 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Semantic differences between two versions of the registry.
+//!
+//! Every network becomes a [`Ss58AddressFormatRegistry`](crate::Ss58AddressFormatRegistry)
+//! variant and every token symbol a [`TokenRegistry`](crate::TokenRegistry) variant, so some
+//! edits of the registry file are breaking changes of the Rust API.
+
+use crate::json::{
+	network_variant as variant, token_variant, AccountType, NetworkStatus, Registry, SignatureType,
+	TokenMetadata,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A single change between two versions of the registry.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Change {
+	/// A network was added.
+	NetworkAdded {
+		/// Prefix of the network.
+		prefix: u16,
+		/// Name of the network.
+		network: String,
+	},
+	/// A network was removed.
+	NetworkRemoved {
+		/// Prefix of the network.
+		prefix: u16,
+		/// Name of the network.
+		network: String,
+	},
+	/// The network using a prefix was renamed. This only breaks the Rust API if the name of its
+	/// `Ss58AddressFormatRegistry` variant changes too.
+	NetworkRenamed {
+		/// Prefix of the network.
+		prefix: u16,
+		/// Previous name of the network.
+		old: String,
+		/// New name of the network.
+		new: String,
+	},
+	/// The display name of a network changed.
+	DisplayNameChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous display name.
+		old: String,
+		/// New display name.
+		new: String,
+	},
+	/// The website of a network changed.
+	WebsiteChanged {
+		/// Name of the network.
+		network: String,
+		/// New website.
+		new: Option<String>,
+	},
+	/// The token symbols of a network changed.
+	SymbolsChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous symbols.
+		old: Vec<String>,
+		/// New symbols.
+		new: Vec<String>,
+	},
+	/// The decimals of a token of a network changed.
+	DecimalsChanged {
+		/// Name of the network.
+		network: String,
+		/// Symbol of the token.
+		symbol: String,
+		/// Previous decimals.
+		old: u8,
+		/// New decimals.
+		new: u8,
+	},
+	/// The standard account of a network changed.
+	StandardAccountChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous standard account, `None` if the network was reserved.
+		old: Option<SignatureType>,
+		/// New standard account, `None` if the network is reserved now.
+		new: Option<SignatureType>,
+	},
+	/// The genesis hashes of a network changed.
+	GenesisHashesChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous genesis hashes.
+		old: Vec<String>,
+		/// New genesis hashes.
+		new: Vec<String>,
+	},
+	/// The relay chain or para id of a network changed.
+	ParachainChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous relay chain and para id.
+		old: Option<(String, u32)>,
+		/// New relay chain and para id.
+		new: Option<(String, u32)>,
+	},
+	/// The status of a network changed.
+	StatusChanged {
+		/// Name of the network.
+		network: String,
+		/// Previous status.
+		old: NetworkStatus,
+		/// New status.
+		new: NetworkStatus,
+	},
+	/// The block explorer links of a network changed.
+	ExplorerChanged {
+		/// Name of the network.
+		network: String,
+	},
+	/// A token symbol got a `TokenRegistry` variant.
+	TokenAdded {
+		/// Symbol of the token.
+		symbol: String,
+	},
+	/// A token symbol lost its `TokenRegistry` variant, because no network uses it any more or
+	/// it was scoped to the networks using it.
+	TokenRemoved {
+		/// Symbol of the token.
+		symbol: String,
+	},
+	/// The symbol of a `TokenRegistry` variant changed without changing the variant name, e.g.
+	/// only in case.
+	TokenRenamed {
+		/// Previous symbol.
+		old: String,
+		/// New symbol.
+		new: String,
+	},
+	/// Several symbols would become the same `TokenRegistry` variant.
+	TokenVariantCollision {
+		/// Name of the variant.
+		variant: String,
+		/// The symbols.
+		symbols: Vec<String>,
+	},
+	/// A metadata field of a token, like its name or existential deposit, changed.
+	TokenMetadataChanged {
+		/// Symbol of the token.
+		symbol: String,
+		/// Name of the field in the registry file.
+		field: &'static str,
+		/// Previous value, `None` if it was not set.
+		old: Option<String>,
+		/// New value, `None` if it is not set any more.
+		new: Option<String>,
+	},
+	/// A named denomination was appended to a token.
+	DenominationAdded {
+		/// Symbol of the token.
+		symbol: String,
+		/// Name of the denomination.
+		name: String,
+		/// Decimals of the denomination.
+		decimals: u8,
+	},
+	/// A named denomination of a token was removed.
+	DenominationRemoved {
+		/// Symbol of the token.
+		symbol: String,
+		/// Name of the denomination.
+		name: String,
+	},
+	/// The denomination at some index of a token got another name.
+	DenominationRenamed {
+		/// Symbol of the token.
+		symbol: String,
+		/// Previous name of the denomination.
+		old: String,
+		/// New name of the denomination.
+		new: String,
+	},
+	/// The decimals of a named denomination changed.
+	DenominationDecimalsChanged {
+		/// Symbol of the token.
+		symbol: String,
+		/// Name of the denomination.
+		name: String,
+		/// Previous decimals.
+		old: u8,
+		/// New decimals.
+		new: u8,
+	},
+}
+
+impl Change {
+	/// Whether the change adds an enum variant to the generated Rust API.
+	pub fn is_addition(&self) -> bool {
+		matches!(self, Change::NetworkAdded { .. } | Change::TokenAdded { .. })
+	}
+
+	/// Whether the change breaks users of the generated Rust API, e.g. by removing or renaming
+	/// an enum variant or changing the decimals of a token.
+	pub fn is_breaking(&self) -> bool {
+		match self {
+			Change::NetworkRemoved { .. } |
+			Change::DecimalsChanged { .. } |
+			Change::TokenRemoved { .. } |
+			Change::TokenVariantCollision { .. } |
+			// Denominations are looked up by index in `TokenRegistry::denominations()`.
+			Change::DenominationRemoved { .. } |
+			Change::DenominationRenamed { .. } |
+			Change::DenominationDecimalsChanged { .. } => true,
+			Change::NetworkRenamed { old, new, .. } => variant(old) != variant(new),
+			// Appending symbols keeps the index of existing tokens.
+			Change::SymbolsChanged { old, new, .. } => !new.starts_with(old),
+			// A network becoming reserved changes `is_reserved()`.
+			Change::StandardAccountChanged { old, new, .. } => old.is_some() && new.is_none(),
+			Change::NetworkAdded { .. } |
+			Change::DisplayNameChanged { .. } |
+			Change::WebsiteChanged { .. } |
+			Change::GenesisHashesChanged { .. } |
+			Change::ParachainChanged { .. } |
+			Change::StatusChanged { .. } |
+			Change::ExplorerChanged { .. } |
+			Change::TokenAdded { .. } |
+			Change::TokenRenamed { .. } |
+			Change::TokenMetadataChanged { .. } |
+			Change::DenominationAdded { .. } => false,
+		}
+	}
+}
+
+fn status(status: &NetworkStatus) -> String {
+	serde_json::to_value(status).expect("serializable").to_string()
+}
+
+fn parachain(parachain: &Option<(String, u32)>) -> String {
+	match parachain {
+		Some((relay_chain, para_id)) => format!("`{}` (para id {})", relay_chain, para_id),
+		None => "none".into(),
+	}
+}
+
+fn symbols(symbols: &[String]) -> String {
+	let symbols: Vec<_> = symbols.iter().map(|s| format!("`{}`", s)).collect();
+	if symbols.is_empty() {
+		"none".into()
+	} else {
+		symbols.join(", ")
+	}
+}
+
+fn standard_account(account: &Option<SignatureType>) -> String {
+	match account {
+		Some(account) => serde_json::to_value(account).expect("serializable").to_string(),
+		None => "reserved".into(),
+	}
+}
+
+impl std::fmt::Display for Change {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Change::NetworkAdded { prefix, network } => write!(
+				f,
+				"Added network `{}` with prefix {} (`{}`)",
+				network,
+				prefix,
+				variant(network)
+			),
+			Change::NetworkRemoved { prefix, network } => write!(
+				f,
+				"Removed network `{}` with prefix {} (`{}`)",
+				network,
+				prefix,
+				variant(network)
+			),
+			Change::NetworkRenamed { prefix, old, new } if variant(old) == variant(new) => write!(
+				f,
+				"Renamed network `{}` with prefix {} to `{}` (`{}` is unchanged)",
+				old,
+				prefix,
+				new,
+				variant(new)
+			),
+			Change::NetworkRenamed { prefix, old, new } => write!(
+				f,
+				"Renamed network `{}` with prefix {} to `{}` (`{}` becomes `{}`)",
+				old,
+				prefix,
+				new,
+				variant(old),
+				variant(new)
+			),
+			Change::DisplayNameChanged { network, old, new } =>
+				write!(f, "Renamed `{}` from \"{}\" to \"{}\"", network, old, new),
+			Change::WebsiteChanged { network, new: Some(new) } =>
+				write!(f, "Changed website of `{}` to <{}>", network, new),
+			Change::WebsiteChanged { network, new: None } =>
+				write!(f, "Removed website of `{}`", network),
+			Change::SymbolsChanged { network, old, new } => write!(
+				f,
+				"Changed symbols of `{}` from {} to {}",
+				network,
+				symbols(old),
+				symbols(new)
+			),
+			Change::DecimalsChanged { network, symbol, old, new } => write!(
+				f,
+				"Changed decimals of `{}` on `{}` from {} to {}",
+				symbol, network, old, new
+			),
+			Change::StandardAccountChanged { network, old, new } => write!(
+				f,
+				"Changed standard account of `{}` from {} to {}",
+				network,
+				standard_account(old),
+				standard_account(new)
+			),
+			Change::GenesisHashesChanged { network, old, new } => write!(
+				f,
+				"Changed genesis hashes of `{}` from {} to {}",
+				network,
+				symbols(old),
+				symbols(new)
+			),
+			Change::ParachainChanged { network, old, new } => write!(
+				f,
+				"Changed relay chain of `{}` from {} to {}",
+				network,
+				parachain(old),
+				parachain(new)
+			),
+			Change::StatusChanged { network, old, new } =>
+				write!(f, "Changed status of `{}` from {} to {}", network, status(old), status(new)),
+			Change::ExplorerChanged { network } =>
+				write!(f, "Changed explorer links of `{}`", network),
+			Change::TokenAdded { symbol } => write!(f, "Added token `{}`", symbol),
+			Change::TokenRemoved { symbol } => write!(f, "Removed token `{}`", symbol),
+			Change::TokenRenamed { old, new } => write!(
+				f,
+				"Renamed token `{}` to `{}` (`{}` is unchanged)",
+				old,
+				new,
+				token_variant(new)
+			),
+			Change::TokenVariantCollision { variant, symbols: colliding } => write!(
+				f,
+				"Tokens {} would all become `TokenRegistry::{}`",
+				symbols(colliding),
+				variant
+			),
+			Change::TokenMetadataChanged { symbol, field, old, new } => match (old, new) {
+				(None, Some(new)) => write!(f, "Set {} of `{}` to `{}`", field, symbol, new),
+				(Some(_), None) => write!(f, "Removed {} of `{}`", field, symbol),
+				_ => write!(
+					f,
+					"Changed {} of `{}` from `{}` to `{}`",
+					field,
+					symbol,
+					old.as_deref().unwrap_or_default(),
+					new.as_deref().unwrap_or_default()
+				),
+			},
+			Change::DenominationAdded { symbol, name, decimals } => write!(
+				f,
+				"Added denomination `{}` of `{}` with {} decimals",
+				name, symbol, decimals
+			),
+			Change::DenominationRemoved { symbol, name } =>
+				write!(f, "Removed denomination `{}` of `{}`", name, symbol),
+			Change::DenominationRenamed { symbol, old, new } =>
+				write!(f, "Renamed denomination `{}` of `{}` to `{}`", old, symbol, new),
+			Change::DenominationDecimalsChanged { symbol, name, old, new } => write!(
+				f,
+				"Changed decimals of denomination `{}` of `{}` from {} to {}",
+				name, symbol, old, new
+			),
+		}
+	}
+}
+
+/// All changes between two versions of the registry.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct RegistryDiff {
+	/// The changes, ordered by prefix of the network they affect, then token changes.
+	pub changes: Vec<Change>,
+}
+
+impl RegistryDiff {
+	/// Whether any of the changes is breaking.
+	pub fn is_breaking(&self) -> bool {
+		self.changes.iter().any(Change::is_breaking)
+	}
+
+	/// Renders the changes as Markdown suitable for `CHANGELOG.md`.
+	pub fn to_markdown(&self) -> String {
+		let mut out = String::new();
+		let (breaking, other): (Vec<_>, Vec<_>) =
+			self.changes.iter().partition(|change| change.is_breaking());
+		for (title, changes) in [("Breaking changes", breaking), ("Non-breaking changes", other)] {
+			if changes.is_empty() {
+				continue
+			}
+			if !out.is_empty() {
+				out.push('\n');
+			}
+			out.push_str(&format!("### {}\n\n", title));
+			for change in changes {
+				out.push_str(&format!("- {}\n", change));
+			}
+		}
+		out
+	}
+}
+
+fn diff_account(old: &AccountType, new: &AccountType, changes: &mut Vec<Change>) {
+	let network = new.network.clone();
+	if old.network != new.network {
+		changes.push(Change::NetworkRenamed {
+			prefix: new.prefix,
+			old: old.network.clone(),
+			new: new.network.clone(),
+		});
+	}
+	if old.display_name != new.display_name {
+		changes.push(Change::DisplayNameChanged {
+			network: network.clone(),
+			old: old.display_name.clone(),
+			new: new.display_name.clone(),
+		});
+	}
+	if old.symbols != new.symbols {
+		changes.push(Change::SymbolsChanged {
+			network: network.clone(),
+			old: old.symbols.clone(),
+			new: new.symbols.clone(),
+		});
+	}
+	for (symbol, new_decimals) in new.symbols.iter().zip(&new.decimals) {
+		let old_decimals = old.symbols.iter().zip(&old.decimals).find(|(s, _)| *s == symbol);
+		if let Some((_, old_decimals)) = old_decimals {
+			if old_decimals != new_decimals {
+				changes.push(Change::DecimalsChanged {
+					network: network.clone(),
+					symbol: symbol.clone(),
+					old: *old_decimals,
+					new: *new_decimals,
+				});
+			}
+		}
+	}
+	if old.standard_account != new.standard_account {
+		changes.push(Change::StandardAccountChanged {
+			network: network.clone(),
+			old: old.standard_account,
+			new: new.standard_account,
+		});
+	}
+	if old.website != new.website {
+		changes.push(Change::WebsiteChanged { network: network.clone(), new: new.website.clone() });
+	}
+	if old.genesis_hashes != new.genesis_hashes {
+		changes.push(Change::GenesisHashesChanged {
+			network: network.clone(),
+			old: old.genesis_hashes.clone(),
+			new: new.genesis_hashes.clone(),
+		});
+	}
+	// A network becoming reserved is already reported as a standard account change.
+	let (old_status, new_status) = (old.effective_status(), new.effective_status());
+	if old_status != new_status && old.is_reserved() == new.is_reserved() {
+		changes.push(Change::StatusChanged {
+			network: network.clone(),
+			old: old_status,
+			new: new_status,
+		});
+	}
+	let parachain = |a: &AccountType| a.relay_chain.clone().zip(a.para_id);
+	if parachain(old) != parachain(new) {
+		changes.push(Change::ParachainChanged {
+			network: network.clone(),
+			old: parachain(old),
+			new: parachain(new),
+		});
+	}
+	if old.explorer != new.explorer {
+		changes.push(Change::ExplorerChanged { network });
+	}
+}
+
+fn diff_token(old: &TokenMetadata, new: &TokenMetadata, changes: &mut Vec<Change>) {
+	let symbol = &new.symbol;
+	let fields = [
+		("name", old.name.clone(), new.name.clone()),
+		("assetId", old.asset_id.map(|id| id.to_string()), new.asset_id.map(|id| id.to_string())),
+		("existentialDeposit", old.existential_deposit.clone(), new.existential_deposit.clone()),
+		("slip44", old.slip44.map(|s| s.to_string()), new.slip44.map(|s| s.to_string())),
+		("coingeckoId", old.coingecko_id.clone(), new.coingecko_id.clone()),
+	];
+	for (field, old, new) in fields {
+		if old != new {
+			changes.push(Change::TokenMetadataChanged { symbol: symbol.clone(), field, old, new });
+		}
+	}
+	// Denominations are compared by index, as that is how they are exposed.
+	for index in 0..old.denominations.len().max(new.denominations.len()) {
+		match (old.denominations.get(index), new.denominations.get(index)) {
+			(Some(old), Some(new)) => {
+				if old.name != new.name {
+					changes.push(Change::DenominationRenamed {
+						symbol: symbol.clone(),
+						old: old.name.clone(),
+						new: new.name.clone(),
+					});
+				}
+				if old.decimals != new.decimals {
+					changes.push(Change::DenominationDecimalsChanged {
+						symbol: symbol.clone(),
+						name: new.name.clone(),
+						old: old.decimals,
+						new: new.decimals,
+					});
+				}
+			},
+			(Some(old), None) => changes.push(Change::DenominationRemoved {
+				symbol: symbol.clone(),
+				name: old.name.clone(),
+			}),
+			(None, Some(new)) => changes.push(Change::DenominationAdded {
+				symbol: symbol.clone(),
+				name: new.name.clone(),
+				decimals: new.decimals,
+			}),
+			(None, None) => unreachable!("index is below the length of either list"),
+		}
+	}
+}
+
+/// Computes the changes from the `old` to the `new` registry. Networks are matched by prefix,
+/// token metadata by symbol.
+pub fn diff(old: &Registry, new: &Registry) -> RegistryDiff {
+	let old_accounts: BTreeMap<_, _> = old.accounts.iter().map(|a| (a.prefix, a)).collect();
+	let new_accounts: BTreeMap<_, _> = new.accounts.iter().map(|a| (a.prefix, a)).collect();
+	let prefixes: BTreeSet<_> = old_accounts.keys().chain(new_accounts.keys()).collect();
+
+	let mut changes = Vec::new();
+	for prefix in prefixes {
+		match (old_accounts.get(prefix), new_accounts.get(prefix)) {
+			(Some(old), Some(new)) => diff_account(old, new, &mut changes),
+			(Some(old), None) => changes
+				.push(Change::NetworkRemoved { prefix: *prefix, network: old.network.clone() }),
+			(None, Some(new)) =>
+				changes.push(Change::NetworkAdded { prefix: *prefix, network: new.network.clone() }),
+			(None, None) => unreachable!("prefix is taken from either registry"),
+		}
+	}
+
+	// Tokens are matched by the name of their variant, like networks by prefix.
+	let by_variant = |registry: &Registry| {
+		let mut variants = BTreeMap::<String, Vec<String>>::new();
+		for symbol in registry.global_symbols() {
+			variants.entry(token_variant(&symbol)).or_default().push(symbol);
+		}
+		variants
+	};
+	let (old_tokens, new_tokens) = (by_variant(old), by_variant(new));
+	for (variant, old_symbols) in &old_tokens {
+		match new_tokens.get(variant) {
+			None => changes.extend(
+				old_symbols.iter().map(|symbol| Change::TokenRemoved { symbol: symbol.clone() }),
+			),
+			Some(new_symbols) if new_symbols.len() > 1 => {},
+			Some(new_symbols) if *old_symbols != *new_symbols =>
+				changes.push(Change::TokenRenamed {
+					old: old_symbols[0].clone(),
+					new: new_symbols[0].clone(),
+				}),
+			Some(_) => {},
+		}
+	}
+	for (variant, new_symbols) in &new_tokens {
+		if !old_tokens.contains_key(variant) && new_symbols.len() == 1 {
+			changes.push(Change::TokenAdded { symbol: new_symbols[0].clone() });
+		}
+	}
+	for (variant, new_symbols) in new_tokens {
+		if new_symbols.len() > 1 {
+			changes.push(Change::TokenVariantCollision { variant, symbols: new_symbols });
+		}
+	}
+
+	let old_metadata: BTreeMap<_, _> = old.tokens.iter().map(|t| (&t.symbol, t)).collect();
+	let new_metadata: BTreeMap<_, _> = new.tokens.iter().map(|t| (&t.symbol, t)).collect();
+	for symbol in old_metadata.keys().chain(new_metadata.keys()).collect::<BTreeSet<_>>() {
+		let none = TokenMetadata { symbol: (*symbol).clone(), ..Default::default() };
+		let old = old_metadata.get(symbol).copied().unwrap_or(&none);
+		let new = new_metadata.get(symbol).copied().unwrap_or(&none);
+		diff_token(old, new, &mut changes);
+	}
+
+	RegistryDiff { changes }
+}
+
+/// Version bump required for a release containing some changes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Bump {
+	/// No change at all, any version will do.
+	None,
+	/// Changes to the data of existing networks and tokens only, requires a patch version bump.
+	Patch,
+	/// Added networks or tokens, requires a minor version bump.
+	Minor,
+	/// Breaking changes, requires a major version bump.
+	Major,
+}
+
+impl RegistryDiff {
+	/// The version bump the changes require.
+	pub fn required_bump(&self) -> Bump {
+		if self.is_breaking() {
+			Bump::Major
+		} else if self.changes.iter().any(Change::is_addition) {
+			Bump::Minor
+		} else if self.changes.is_empty() {
+			Bump::None
+		} else {
+			Bump::Patch
+		}
+	}
+}
+
+/// A `major.minor.patch` crate version.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Version {
+	/// Major version.
+	pub major: u64,
+	/// Minor version.
+	pub minor: u64,
+	/// Patch version.
+	pub patch: u64,
+}
+
+impl std::str::FromStr for Version {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		// Pre-release and build metadata do not matter for the bump.
+		let core = s.trim().split(['-', '+']).next().unwrap_or_default();
+		let parts = core
+			.split('.')
+			.map(|part| part.parse().map_err(|_| format!("invalid version `{}`", s)))
+			.collect::<Result<Vec<u64>, _>>()?;
+		match parts[..] {
+			[major, minor, patch] => Ok(Version { major, minor, patch }),
+			_ => Err(format!("invalid version `{}`", s)),
+		}
+	}
+}
+
+impl std::fmt::Display for Version {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+	}
+}
+
+impl Version {
+	/// Reads the package version from the contents of a `Cargo.toml`.
+	pub fn from_manifest(manifest: &str) -> Result<Self, String> {
+		let mut in_package = false;
+		for line in manifest.lines().map(str::trim) {
+			if line.starts_with('[') {
+				in_package = line == "[package]";
+			} else if let Some(value) = line.strip_prefix("version").map(str::trim_start) {
+				if in_package {
+					if let Some(value) = value.strip_prefix('=') {
+						return value.trim().trim_matches('"').parse()
+					}
+				}
+			}
+		}
+		Err("no package version found in manifest".into())
+	}
+
+	/// Whether going from `self` to `new` is a large enough bump, following Cargo's semver
+	/// rules where `0.x` releases bump the minor version for breaking changes.
+	pub fn satisfies(&self, new: &Version, bump: Bump) -> bool {
+		let (old_compat, new_compat) = if self.major == 0 {
+			((0, self.minor), (new.major, new.minor))
+		} else {
+			((self.major, 0), (new.major, 0))
+		};
+		match bump {
+			Bump::Major => new_compat > old_compat,
+			Bump::Minor if self.major == 0 => new > self,
+			Bump::Minor => (new.major, new.minor) > (self.major, self.minor),
+			Bump::Patch => new > self,
+			Bump::None => new >= self,
+		}
+	}
+}
+
+/// Checks that the version was bumped from `old` to `new` as required by the changes of the
+/// registry.
+pub fn check_version_bump(diff: &RegistryDiff, old: &Version, new: &Version) -> Result<(), String> {
+	let bump = diff.required_bump();
+	if old.satisfies(new, bump) {
+		return Ok(())
+	}
+	// List the changes that call for the bump.
+	let listed = |filter: fn(&Change) -> bool| {
+		let changes: Vec<_> =
+			diff.changes.iter().filter(|c| filter(c)).map(|c| format!("- {}", c)).collect();
+		changes.join("\n")
+	};
+	let reason = match bump {
+		Bump::Major => format!(
+			"breaking changes require a major version bump:\n{}",
+			listed(Change::is_breaking)
+		),
+		Bump::Minor => format!(
+			"added networks or tokens require a minor version bump:\n{}",
+			listed(Change::is_addition)
+		),
+		Bump::Patch => format!("changes require a patch version bump:\n{}", listed(|_| true)),
+		Bump::None => "the version must not go backwards".to_owned(),
+	};
+	Err(format!("version {} is not a valid successor of {}: {}", new, old, reason))
+}
@@ -27,3 +27,232 @@ impl std::fmt::Display for ParseError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
+
+/// Error encountered while parsing a [`TokenAmount`](crate::TokenAmount) or
+/// [`TokenDelta`](crate::TokenDelta) from &'_ str
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ParseAmountError {
+	/// The number is malformed.
+	InvalidNumber,
+	/// The number has more fractional digits than the token has decimals.
+	TooManyDecimals,
+	/// The number does not fit the amount type.
+	Overflow,
+	/// The symbol following the number is not the one of the token.
+	TokenMismatch,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ParseAmountError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ParseAmountError::InvalidNumber => write!(f, "invalid number"),
+			ParseAmountError::TooManyDecimals => write!(f, "too many decimals for token"),
+			ParseAmountError::Overflow => write!(f, "amount overflows"),
+			ParseAmountError::TokenMismatch => write!(f, "symbol does not match token"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAmountError {}
+
+/// Error encountered while encoding or decoding an SS58 address.
+#[cfg(feature = "ss58")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AddressError {
+	/// The address is not valid base58.
+	BadBase58,
+	/// The address or account has an unsupported length.
+	BadLength,
+	/// The checksum of the address does not match.
+	InvalidChecksum,
+	/// The prefix cannot be represented in an SS58 address.
+	InvalidPrefix,
+}
+
+#[cfg(feature = "ss58")]
+impl std::fmt::Display for AddressError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			AddressError::BadBase58 => write!(f, "invalid base58"),
+			AddressError::BadLength => write!(f, "invalid length"),
+			AddressError::InvalidChecksum => write!(f, "invalid checksum"),
+			AddressError::InvalidPrefix => write!(f, "invalid prefix"),
+		}
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::error::Error for AddressError {}
+
+/// Error encountered while parsing or building a CAIP-2 chain id or CAIP-10 account id.
+#[cfg(feature = "ss58")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CaipError {
+	/// The id is not in the `polkadot` namespace.
+	InvalidNamespace,
+	/// The chain reference is not 32 lowercase hex digits.
+	InvalidReference,
+	/// The chain is not in the registry.
+	UnknownChain,
+	/// The address is not valid.
+	Address(AddressError),
+	/// The address does not use the format of the network of the chain.
+	FormatMismatch,
+}
+
+#[cfg(feature = "ss58")]
+impl From<AddressError> for CaipError {
+	fn from(x: AddressError) -> Self {
+		CaipError::Address(x)
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::fmt::Display for CaipError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			CaipError::InvalidNamespace => write!(f, "not in the polkadot namespace"),
+			CaipError::InvalidReference => write!(f, "invalid chain reference"),
+			CaipError::UnknownChain => write!(f, "unknown chain"),
+			CaipError::Address(err) => write!(f, "invalid address: {}", err),
+			CaipError::FormatMismatch => write!(f, "address format does not match the chain"),
+		}
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::error::Error for CaipError {}
+
+/// Error encountered while working with Ethereum style [`H160`](crate::H160) accounts.
+#[cfg(feature = "ethereum")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EthereumError {
+	/// The standard account of the network is not secp256k1.
+	NotSecp256k1,
+	/// The address does not use the format of the network.
+	FormatMismatch,
+	/// The account is not a 33 byte compressed secp256k1 public key.
+	InvalidPublicKey,
+	/// The account is not a 32 byte substrate account.
+	InvalidAccount,
+	/// The H160 address is not `0x` followed by 40 hex digits.
+	InvalidAddress,
+	/// The mixed case H160 address does not have a valid EIP-55 checksum.
+	InvalidChecksum,
+	/// The SS58 address is not valid.
+	Address(AddressError),
+}
+
+#[cfg(feature = "ethereum")]
+impl From<AddressError> for EthereumError {
+	fn from(x: AddressError) -> Self {
+		EthereumError::Address(x)
+	}
+}
+
+#[cfg(feature = "ethereum")]
+impl std::fmt::Display for EthereumError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			EthereumError::NotSecp256k1 => write!(f, "standard account is not secp256k1"),
+			EthereumError::FormatMismatch => write!(f, "address format does not match the network"),
+			EthereumError::InvalidPublicKey => write!(f, "not a compressed secp256k1 public key"),
+			EthereumError::InvalidAccount => write!(f, "not a 32 byte account"),
+			EthereumError::InvalidAddress => write!(f, "invalid H160 address"),
+			EthereumError::InvalidChecksum => write!(f, "invalid EIP-55 checksum"),
+			EthereumError::Address(err) => write!(f, "invalid address: {}", err),
+		}
+	}
+}
+
+#[cfg(feature = "ethereum")]
+impl std::error::Error for EthereumError {}
+
+/// Error encountered while parsing or building a `substrate:` URI.
+#[cfg(feature = "ss58")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum UriError {
+	/// The URI does not start with `substrate:`.
+	InvalidScheme,
+	/// The genesis hash is not `0x` followed by 64 hex digits.
+	InvalidGenesisHash,
+	/// The address is not valid.
+	Address(AddressError),
+	/// The address does not use the format of the network of the chain.
+	FormatMismatch {
+		/// Format of the address.
+		address: crate::Ss58AddressFormat,
+		/// Network of the chain with the genesis hash.
+		network: crate::Ss58AddressFormatRegistry,
+	},
+}
+
+#[cfg(feature = "ss58")]
+impl From<AddressError> for UriError {
+	fn from(x: AddressError) -> Self {
+		UriError::Address(x)
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::fmt::Display for UriError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			UriError::InvalidScheme => write!(f, "not a substrate URI"),
+			UriError::InvalidGenesisHash => write!(f, "invalid genesis hash"),
+			UriError::Address(err) => write!(f, "invalid address: {}", err),
+			UriError::FormatMismatch { address, network } => write!(
+				f,
+				"address has prefix {} but the chain uses {} (prefix {})",
+				address.prefix(),
+				network,
+				crate::Ss58AddressFormat::from(*network).prefix()
+			),
+		}
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::error::Error for UriError {}
+
+/// Error encountered while deriving a multisig account.
+#[cfg(feature = "ss58")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MultisigError {
+	/// There are fewer than two signatories.
+	TooFewSignatories,
+	/// A signatory is given more than once.
+	DuplicateSignatory,
+	/// The threshold is zero or above the number of signatories.
+	InvalidThreshold,
+	/// A signatory is not a 32 byte account.
+	InvalidAccount,
+	/// An address is not valid.
+	Address(AddressError),
+}
+
+#[cfg(feature = "ss58")]
+impl From<AddressError> for MultisigError {
+	fn from(x: AddressError) -> Self {
+		MultisigError::Address(x)
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::fmt::Display for MultisigError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MultisigError::TooFewSignatories => write!(f, "at least two signatories are required"),
+			MultisigError::DuplicateSignatory => write!(f, "duplicate signatory"),
+			MultisigError::InvalidThreshold =>
+				write!(f, "threshold must be between one and the number of signatories"),
+			MultisigError::InvalidAccount => write!(f, "signatory is not a 32 byte account"),
+			MultisigError::Address(err) => write!(f, "invalid address: {}", err),
+		}
+	}
+}
+
+#[cfg(feature = "ss58")]
+impl std::error::Error for MultisigError {}
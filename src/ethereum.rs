@@ -0,0 +1,161 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ethereum style 20 byte accounts of networks whose standard account is secp256k1.
+//!
+//! The SS58 form of such an account is the 33 byte compressed public key, while the
+//! [`H160`] form is the last 20 bytes of the keccak-256 hash of the uncompressed key, written
+//! with the [EIP-55](https://eips.ethereum.org/EIPS/eip-55) mixed case checksum. As it is a
+//! hash, only the SS58 form can be converted to the H160 form.
+//!
+//! Frontier based chains additionally map every H160 to a 32 byte substrate account, the
+//! blake2 hash of `evm:` followed by the H160, and a 32 byte account back to the H160 made of
+//! its first 20 bytes.
+
+use super::*;
+use crate::{hex_decode::decode_hex, ss58::blake2_256};
+use core::{fmt, str::FromStr};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use tiny_keccak::{Hasher, Keccak};
+
+fn keccak_256(data: &[u8]) -> [u8; 32] {
+	let mut hasher = Keccak::v256();
+	hasher.update(data);
+	let mut hash = [0; 32];
+	hasher.finalize(&mut hash);
+	hash
+}
+
+/// A 20 byte Ethereum style account, displayed as an EIP-55 checksummed hex string.
+///
+/// ```
+/// # use ss58_registry::H160;
+/// let account: H160 = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+/// assert_eq!(account.to_string(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// assert!("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse::<H160>().is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct H160(pub [u8; 20]);
+
+impl H160 {
+	/// The account of a 33 byte compressed secp256k1 public key.
+	pub fn from_public_key(public: &[u8]) -> Result<Self, EthereumError> {
+		if public.len() != 33 {
+			return Err(EthereumError::InvalidPublicKey)
+		}
+		let public = k256::PublicKey::from_sec1_bytes(public)
+			.map_err(|_| EthereumError::InvalidPublicKey)?;
+		let uncompressed = public.to_encoded_point(false);
+		let hash = keccak_256(&uncompressed.as_bytes()[1..]);
+		let mut account = [0; 20];
+		account.copy_from_slice(&hash[12..]);
+		Ok(H160(account))
+	}
+
+	/// The H160 of a 32 byte substrate account, made of its first 20 bytes.
+	pub fn from_substrate_account(account: &[u8; 32]) -> Self {
+		let mut truncated = [0; 20];
+		truncated.copy_from_slice(&account[..20]);
+		H160(truncated)
+	}
+
+	/// The H160 of the 32 byte substrate account encoded in an SS58 address.
+	pub fn from_substrate_address(address: &str) -> Result<Self, EthereumError> {
+		let (_, account) = Ss58AddressFormat::decode(address)?;
+		let account = account.try_into().map_err(|_| EthereumError::InvalidAccount)?;
+		Ok(H160::from_substrate_account(&account))
+	}
+
+	/// The 32 byte substrate account the H160 is mapped to: the blake2 hash of `evm:` followed
+	/// by the H160.
+	pub fn substrate_account(&self) -> [u8; 32] {
+		blake2_256(&[b"evm:", &self.0])
+	}
+
+	/// The SS58 address of the substrate account the H160 is mapped to.
+	///
+	/// ```
+	/// # use ss58_registry::{H160, Ss58AddressFormat, Ss58AddressFormatRegistry};
+	/// let account: H160 = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf".parse().unwrap();
+	/// let format = Ss58AddressFormatRegistry::SubstrateAccount.into();
+	/// let address = account.substrate_address(format).unwrap();
+	/// assert_eq!(Ss58AddressFormat::decode(&address).unwrap().1, account.substrate_account());
+	/// ```
+	pub fn substrate_address(&self, format: Ss58AddressFormat) -> Result<String, AddressError> {
+		format.encode(&self.substrate_account())
+	}
+
+	/// The raw bytes of the account.
+	pub fn as_bytes(&self) -> &[u8; 20] {
+		&self.0
+	}
+}
+
+impl From<[u8; 20]> for H160 {
+	fn from(x: [u8; 20]) -> Self {
+		H160(x)
+	}
+}
+
+impl fmt::Display for H160 {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let lower: String = self.0.iter().map(|byte| format!("{:02x}", byte)).collect();
+		let hash = keccak_256(lower.as_bytes());
+		let checksummed: String = lower
+			.chars()
+			.enumerate()
+			.map(|(i, c)| {
+				let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+				if nibble >= 8 {
+					c.to_ascii_uppercase()
+				} else {
+					c
+				}
+			})
+			.collect();
+		write!(f, "0x{}", checksummed)
+	}
+}
+
+impl FromStr for H160 {
+	type Err = EthereumError;
+
+	/// Parses a `0x` prefixed hex string, checking the EIP-55 checksum if it is mixed case.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let digits = s.strip_prefix("0x").ok_or(EthereumError::InvalidAddress)?;
+		let account = H160(decode_hex(digits).ok_or(EthereumError::InvalidAddress)?);
+		let mixed_case = digits.bytes().any(|d| d.is_ascii_lowercase()) &&
+			digits.bytes().any(|d| d.is_ascii_uppercase());
+		if mixed_case && account.to_string()[2..] != *digits {
+			return Err(EthereumError::InvalidChecksum)
+		}
+		Ok(account)
+	}
+}
+
+impl Ss58AddressFormatRegistry {
+	/// The [`H160`] account of an SS58 address of this network encoding a compressed
+	/// secp256k1 public key.
+	pub fn h160_from_ss58(&self, address: &str) -> Result<H160, EthereumError> {
+		if !self.is_ethereum() {
+			return Err(EthereumError::NotSecp256k1)
+		}
+		let (format, public) = Ss58AddressFormat::decode(address)?;
+		if format != Ss58AddressFormat::from(*self) {
+			return Err(EthereumError::FormatMismatch)
+		}
+		H160::from_public_key(&public)
+	}
+}
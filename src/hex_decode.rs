@@ -0,0 +1,30 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding of hex strings, shared with the build script.
+
+/// Decodes exactly `N` bytes from `2 * N` hex digits of either case, without a `0x` prefix.
+pub(crate) fn decode_hex<const N: usize>(digits: &str) -> Option<[u8; N]> {
+	let digits = digits.as_bytes();
+	if digits.len() != 2 * N {
+		return None
+	}
+	let nibble = |digit: u8| char::from(digit).to_digit(16).map(|n| n as u8);
+	let mut bytes = [0; N];
+	for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+		*byte = nibble(pair[0])? << 4 | nibble(pair[1])?;
+	}
+	Some(bytes)
+}
@@ -0,0 +1,614 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Model of the `ss58-registry.json` file and the rules it has to follow.
+//!
+//! This module is shared with the build script, so the tooling working on the file validates it
+//! exactly like the build does.
+
+use crate::hex_decode::decode_hex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet, HashMap};
+use unicode_xid::UnicodeXID;
+
+/// Highest prefix that can be encoded in an SS58 address.
+const MAX_PREFIX: u16 = 0b0011_1111_1111_1111;
+
+/// The contents of `ss58-registry.json`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Registry {
+	/// Link to the SS58 specification.
+	pub specification: String,
+	/// Description of each field of a registry entry.
+	pub schema: Map<String, Value>,
+	/// Description of each field of a token entry.
+	#[serde(rename = "tokenSchema", default, skip_serializing_if = "Map::is_empty")]
+	pub token_schema: Map<String, Value>,
+	/// The networks.
+	#[serde(rename = "registry")]
+	pub accounts: Vec<AccountType>,
+	/// Additional metadata of tokens used by the networks.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub tokens: Vec<TokenMetadata>,
+	/// Symbols that are only known per network and get no `TokenRegistry` variant, so networks
+	/// may use them with different decimals.
+	#[serde(rename = "scopedSymbols", default, skip_serializing_if = "Vec::is_empty")]
+	pub scoped_symbols: Vec<String>,
+}
+
+/// Name of the `Ss58AddressFormatRegistry` variant of a network.
+pub fn network_variant(network: &str) -> String {
+	format!("{}Account", inflector::cases::pascalcase::to_pascal_case(network))
+}
+
+/// Name of the `TokenRegistry` variant of a token symbol.
+pub fn token_variant(symbol: &str) -> String {
+	inflector::cases::pascalcase::to_pascal_case(symbol)
+}
+
+fn is_valid_rust_identifier(id: &str) -> Result<(), String> {
+	if let Some(ch) = id.chars().next() {
+		if ch.is_xid_start() {
+			for ch in id.chars().skip(1) {
+				if !ch.is_xid_continue() {
+					return Err(format!("Invalid char `{}` in `{}`", ch, id))
+				}
+			}
+			Ok(())
+		} else {
+			Err(format!("`{}` starts with `{}` which is not valid at the start", id, ch))
+		}
+	} else {
+		Err("empty identifier".into())
+	}
+}
+
+impl Registry {
+	/// Parses the registry from its JSON representation.
+	pub fn from_json(json: &str) -> Result<Self, String> {
+		serde_json::from_str(json).map_err(|e| format!("json parsing error: {}", e))
+	}
+
+	/// The decimals each symbol is used with by the networks.
+	fn symbol_decimals(&self) -> BTreeMap<&String, BTreeSet<u8>> {
+		let mut decimals = BTreeMap::<&String, BTreeSet<u8>>::new();
+		for account_type in &self.accounts {
+			for (symbol, d) in account_type.symbols.iter().zip(&account_type.decimals) {
+				decimals.entry(symbol).or_default().insert(*d);
+			}
+		}
+		decimals
+	}
+
+	/// Symbols of the tokens that get a `TokenRegistry` variant: all symbols used by the
+	/// networks except the [scoped](Registry::scoped_symbols) ones.
+	pub fn global_symbols(&self) -> BTreeSet<String> {
+		self.symbol_decimals()
+			.into_keys()
+			.filter(|s| !self.scoped_symbols.contains(s))
+			.cloned()
+			.collect()
+	}
+
+	/// Checks the registry for consistency and returns the tokens shared by its networks, see
+	/// [`Registry::global_symbols`].
+	///
+	/// Networks must agree on the decimals of a symbol unless it is scoped, so a data change
+	/// cannot silently remove a `TokenRegistry` variant.
+	pub fn validate(&self) -> Result<Vec<TokenType>, String> {
+		let symbol_decimals = self.symbol_decimals();
+		for (symbol, decimals) in &symbol_decimals {
+			if decimals.len() > 1 && !self.scoped_symbols.contains(symbol) {
+				return Err(format!(
+					"networks use {} with different decimals {:?}, add it to scopedSymbols to \
+					 only know it per network (this removes its TokenRegistry variant)",
+					symbol, decimals
+				))
+			}
+		}
+		for (i, symbol) in self.scoped_symbols.iter().enumerate() {
+			if !symbol_decimals.contains_key(symbol) {
+				return Err(format!("scoped symbol {} is not used by any network", symbol))
+			}
+			if self.scoped_symbols[..i].contains(symbol) {
+				return Err(format!("scoped symbol {} specified twice", symbol))
+			}
+		}
+		let global_symbols = self.global_symbols();
+		let mut variants = BTreeMap::new();
+		for symbol in &global_symbols {
+			if let Some(clash) = variants.insert(token_variant(symbol), symbol) {
+				return Err(format!(
+					"tokens {} and {} would both become TokenRegistry::{}",
+					clash,
+					symbol,
+					token_variant(symbol)
+				))
+			}
+		}
+		let mut tokens = BTreeMap::<String, TokenType>::new();
+		let mut used_prefixes = HashMap::<u16, AccountType>::new();
+		let mut used_networks = HashMap::<String, AccountType>::new();
+		let mut used_genesis_hashes = HashMap::<[u8; 32], String>::new();
+		for account_type in &self.accounts {
+			if let Some(clash) = used_prefixes.insert(account_type.prefix, (*account_type).clone())
+			{
+				return Err(format!(
+                    "prefixes must be unique but this account's prefix:\n{:#?}\nclashed with\n{:#?}",
+                    account_type,
+                    clash
+                ));
+			}
+			if let Some(clash) = used_networks.insert(account_type.name(), account_type.clone()) {
+				return Err(format!(
+                    "networks must be unique but this account's network:\n{:#?}\nclashed with\n:{:#?}",
+                    account_type,
+                    clash
+                ));
+			}
+			if account_type.network.is_empty() {
+				return Err("network is mandatory.".into())
+			}
+
+			if let Err(err) = is_valid_rust_identifier(&account_type.name()) {
+				return Err(format!("network not valid: {} for {:#?}", err, account_type))
+			}
+			for hash in &account_type.genesis_hashes {
+				if hash.bytes().any(|b| b.is_ascii_uppercase()) {
+					return Err(format!("genesis hash `{}` must be lowercase", hash))
+				}
+				let parsed = parse_genesis_hash(hash)?;
+				if let Some(clash) =
+					used_genesis_hashes.insert(parsed, account_type.network.clone())
+				{
+					return Err(format!(
+						"genesis hash {} of {} is already used by {}",
+						hash, account_type.network, clash
+					))
+				}
+			}
+			if (account_type.effective_status() == NetworkStatus::Reserved) !=
+				account_type.is_reserved()
+			{
+				return Err(format!(
+					"status of {} must be reserved exactly if the standard account is null",
+					account_type.network
+				))
+			}
+			if let Some(explorer) = &account_type.explorer {
+				explorer.validate().map_err(|e| format!("{}: {}", account_type.network, e))?;
+			}
+			if account_type.decimals.len() != account_type.symbols.len() {
+				return Err(format!(
+					"decimals must be specified for each symbol: {:?}",
+					account_type
+				))
+			}
+			for (name, decimals) in account_type.symbols.iter().zip(&account_type.decimals) {
+				use Entry::*;
+				if !global_symbols.contains(name) {
+					continue
+				}
+				let network = account_type.display_name.clone();
+				match tokens.entry(name.to_owned()) {
+					Occupied(mut e) => e.get_mut().networks.push(network),
+					Vacant(e) => {
+						e.insert(TokenType {
+							symbol: name.to_owned(),
+							decimals: *decimals,
+							networks: vec![network],
+							metadata: TokenMetadata {
+								symbol: name.to_owned(),
+								..Default::default()
+							},
+						});
+					},
+				}
+			}
+		}
+		let mut para_ids = BTreeMap::new();
+		for account_type in &self.accounts {
+			let network = &account_type.network;
+			let (relay_chain, para_id) = match (&account_type.relay_chain, account_type.para_id) {
+				(Some(relay_chain), Some(para_id)) => (relay_chain, para_id),
+				(None, None) => continue,
+				_ =>
+					return Err(format!(
+						"relayChain and paraId must be specified together for {}",
+						network
+					)),
+			};
+			match self.accounts.iter().find(|a| &a.network == relay_chain) {
+				None =>
+					return Err(format!(
+						"relay chain {} of {} is not in the registry",
+						relay_chain, network
+					)),
+				Some(relay) if relay.relay_chain.is_some() =>
+					return Err(format!(
+						"relay chain {} of {} is a parachain itself",
+						relay_chain, network
+					)),
+				Some(_) => {},
+			}
+			if let Some(clash) = para_ids.insert((relay_chain, para_id), network) {
+				return Err(format!(
+					"para id {} on {} is used by both {} and {}",
+					para_id, relay_chain, clash, network
+				))
+			}
+		}
+		let mut seen = BTreeSet::new();
+		for metadata in &self.tokens {
+			let token = tokens.get_mut(&metadata.symbol).ok_or_else(|| {
+				format!(
+					"token {} has metadata but is not used by any network or is scoped",
+					metadata.symbol
+				)
+			})?;
+			if !seen.insert(&metadata.symbol) {
+				return Err(format!("metadata for token {} specified twice", metadata.symbol))
+			}
+			metadata.validate()?;
+			for (i, denomination) in metadata.denominations.iter().enumerate() {
+				if denomination.name.trim().is_empty() {
+					return Err(format!("empty denomination name for token {}", metadata.symbol))
+				}
+				if denomination.decimals > token.decimals {
+					return Err(format!(
+						"denomination {} of token {} has {} decimals but the token only has {}",
+						denomination.name, metadata.symbol, denomination.decimals, token.decimals
+					))
+				}
+				if metadata.denominations[..i].iter().any(|d| d.name == denomination.name) {
+					return Err(format!(
+						"denomination {} of token {} specified twice",
+						denomination.name, metadata.symbol
+					))
+				}
+			}
+			token.metadata = metadata.clone();
+		}
+		Ok(tokens.into_values().collect())
+	}
+
+	/// Sorts the networks by prefix and the token metadata and scoped symbols by symbol.
+	pub fn sort(&mut self) {
+		self.accounts.sort_by_key(|a| a.prefix);
+		self.tokens.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+		self.scoped_symbols.sort();
+	}
+
+	/// The lowest prefix that is not yet used by any network.
+	pub fn lowest_free_prefix(&self) -> Option<u16> {
+		let used: BTreeSet<_> = self.accounts.iter().map(|a| a.prefix).collect();
+		(0..=MAX_PREFIX).find(|prefix| !used.contains(prefix))
+	}
+
+	/// Renders the registry as JSON in the formatting of `ss58-registry.json`. The fields of each
+	/// entry are ordered like the `schema` (respectively `tokenSchema`) block.
+	pub fn to_json(&self) -> String {
+		let mut value = serde_json::to_value(self).expect("registry is representable as JSON");
+		for (key, schema) in [("registry", &self.schema), ("tokens", &self.token_schema)] {
+			if let Some(Value::Array(entries)) = value.get_mut(key) {
+				entries.iter_mut().for_each(|entry| order_like_schema(entry, schema));
+			}
+		}
+		let mut out = String::new();
+		write_json(&mut out, &value, 0);
+		out.push('\n');
+		out
+	}
+}
+
+/// Rewrites the registry file contents in canonical form: entries sorted by prefix, tokens sorted
+/// by symbol, fields in schema order and consistent formatting. Fields the registry does not
+/// model are rejected rather than dropped.
+///
+/// ```
+/// # use ss58_registry::json;
+/// let registry = include_str!("../ss58-registry.json");
+/// assert_eq!(json::format(registry).unwrap(), registry);
+/// ```
+pub fn format(json: &str) -> Result<String, String> {
+	let mut registry = Registry::from_json(json)?;
+	registry.sort();
+	Ok(registry.to_json())
+}
+
+/// Orders the fields of `entry` like the keys of `schema`, followed by any fields the schema
+/// does not describe.
+fn order_like_schema(entry: &mut Value, schema: &Map<String, Value>) {
+	if let Value::Object(fields) = entry {
+		let mut ordered = Map::new();
+		for key in schema.keys() {
+			if let Some(value) = fields.remove(key) {
+				ordered.insert(key.clone(), value);
+			}
+		}
+		ordered.append(fields);
+		*fields = ordered;
+	}
+}
+
+/// Writes `value` with two space indentation, keeping arrays of scalars on a single line.
+fn write_json(out: &mut String, value: &Value, indent: usize) {
+	let is_scalar = |v: &Value| !matches!(v, Value::Array(_) | Value::Object(_));
+	match value {
+		Value::Object(map) if !map.is_empty() => {
+			out.push_str("{\n");
+			for (i, (key, value)) in map.iter().enumerate() {
+				out.push_str(&"  ".repeat(indent + 1));
+				out.push_str(&Value::String(key.clone()).to_string());
+				out.push_str(": ");
+				write_json(out, value, indent + 1);
+				out.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+			}
+			out.push_str(&"  ".repeat(indent));
+			out.push('}');
+		},
+		Value::Array(values) if values.iter().all(is_scalar) => {
+			let values: Vec<_> = values.iter().map(Value::to_string).collect();
+			out.push('[');
+			out.push_str(&values.join(", "));
+			out.push(']');
+		},
+		Value::Array(values) => {
+			out.push_str("[\n");
+			for (i, value) in values.iter().enumerate() {
+				out.push_str(&"  ".repeat(indent + 1));
+				write_json(out, value, indent + 1);
+				out.push_str(if i + 1 < values.len() { ",\n" } else { "\n" });
+			}
+			out.push_str(&"  ".repeat(indent));
+			out.push(']');
+		},
+		value => out.push_str(&value.to_string()),
+	}
+}
+
+/// Signing curve of the standard account of a network.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureType {
+	/// Schnorr signatures on Ristretto25519.
+	#[serde(rename = "Sr25519")]
+	Sr25519,
+	/// Ed25519 signatures.
+	#[serde(rename = "Ed25519")]
+	Ed25519,
+	/// ECDSA signatures on secp256k1.
+	#[serde(rename = "secp256k1")]
+	Secp256k1,
+	/// Either of the 25519 curves.
+	#[serde(rename = "*25519")]
+	Any25519,
+}
+
+/// Lifecycle status of a network.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkStatus {
+	/// A production network.
+	Mainnet,
+	/// A test network.
+	Testnet,
+	/// A network that was shut down or replaced.
+	Deprecated,
+	/// The prefix is reserved for future use.
+	Reserved,
+}
+
+/// A network entry of the registry.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AccountType {
+	/// The unique address prefix.
+	pub prefix: u16,
+	/// Unique identifier of the network.
+	pub network: String,
+	/// Name of the network in a format friendly for display.
+	pub display_name: String,
+	/// Symbols of the tokens of the network, ordered by Balances pallet instance.
+	pub symbols: Vec<String>,
+	/// Decimals of each token in `symbols`.
+	pub decimals: Vec<u8>,
+	/// If standard account is None then the network is reserved.
+	pub standard_account: Option<SignatureType>,
+	/// Website or code repository of the network.
+	pub website: Option<String>,
+	/// Hex encoded genesis hashes of the chains using the network.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub genesis_hashes: Vec<String>,
+	/// Network of the relay chain, if the network is a parachain.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub relay_chain: Option<String>,
+	/// Para id of the network on its relay chain.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub para_id: Option<u32>,
+	/// Lifecycle status of the network, see [`AccountType::effective_status`] if missing.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub status: Option<NetworkStatus>,
+	/// Templates of block explorer links.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub explorer: Option<ExplorerTemplates>,
+}
+
+/// Templates of block explorer links of a network, with a placeholder named like the field.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ExplorerTemplates {
+	/// Link to an account, with an `{address}` placeholder.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub address: Option<String>,
+	/// Link to a block, with a `{block}` placeholder for its number or hash.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block: Option<String>,
+	/// Link to an extrinsic, with an `{extrinsic}` placeholder for its hash.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub extrinsic: Option<String>,
+}
+
+impl ExplorerTemplates {
+	/// The templates with the name of their placeholder.
+	pub fn templates(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		[("address", &self.address), ("block", &self.block), ("extrinsic", &self.extrinsic)]
+			.into_iter()
+			.filter_map(|(kind, template)| template.as_deref().map(|t| (kind, t)))
+	}
+
+	/// Checks the templates are links with exactly one placeholder, named like their kind.
+	fn validate(&self) -> Result<(), String> {
+		for (kind, template) in self.templates() {
+			if !template.starts_with("https://") && !template.starts_with("http://") {
+				return Err(format!("{} explorer link `{}` is not an http(s) link", kind, template))
+			}
+			let placeholder = format!("{{{}}}", kind);
+			let rest = template.replacen(&placeholder, "", 1);
+			if rest == template || rest.contains(['{', '}']) {
+				return Err(format!(
+					"{} explorer link `{}` must contain `{}` once and no other placeholder",
+					kind, template, placeholder
+				))
+			}
+		}
+		Ok(())
+	}
+}
+
+impl AccountType {
+	/// Name of the `Ss58AddressFormatRegistry` variant of this network.
+	pub fn name(&self) -> String {
+		network_variant(&self.network)
+	}
+
+	/// Whether the prefix is reserved for future use.
+	pub fn is_reserved(&self) -> bool {
+		self.standard_account.is_none()
+	}
+
+	/// The status of the network, defaulting to mainnet (or reserved if there is no standard
+	/// account).
+	pub fn effective_status(&self) -> NetworkStatus {
+		match self.status {
+			Some(status) => status,
+			None if self.is_reserved() => NetworkStatus::Reserved,
+			None => NetworkStatus::Mainnet,
+		}
+	}
+}
+
+/// Parses a `0x` prefixed, hex encoded genesis hash.
+pub fn parse_genesis_hash(hash: &str) -> Result<[u8; 32], String> {
+	hash.strip_prefix("0x")
+		.and_then(decode_hex)
+		.ok_or_else(|| format!("invalid genesis hash `{}`, expected 0x and 64 hex digits", hash))
+}
+
+/// Additional metadata of a token.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TokenMetadata {
+	/// Symbol of the token as used in the `symbols` of some network.
+	pub symbol: String,
+	/// Full name of the token.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Id of the token in the Assets pallet, `None` for native tokens.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub asset_id: Option<u32>,
+	/// Existential deposit in planck, as a decimal string as it may not fit a JSON number.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub existential_deposit: Option<String>,
+	/// SLIP-0044 coin type.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub slip44: Option<u32>,
+	/// CoinGecko API id.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub coingecko_id: Option<String>,
+	/// Named denominations of the token.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub denominations: Vec<DenominationType>,
+}
+
+impl TokenMetadata {
+	/// The existential deposit in planck.
+	pub fn existential_deposit(&self) -> Result<Option<u128>, String> {
+		self.existential_deposit
+			.as_deref()
+			.map(|deposit| {
+				deposit.parse().map_err(|_| {
+					format!(
+						"existential deposit `{}` of token {} is not an amount of planck",
+						deposit, self.symbol
+					)
+				})
+			})
+			.transpose()
+	}
+
+	/// Checks the metadata apart from the denominations, which need the decimals of the token.
+	fn validate(&self) -> Result<(), String> {
+		if matches!(&self.name, Some(name) if name.trim().is_empty()) {
+			return Err(format!("empty name for token {}", self.symbol))
+		}
+		self.existential_deposit()?;
+		if matches!(self.slip44, Some(coin_type) if coin_type >= 1 << 31) {
+			return Err(format!("slip44 coin type of token {} must be below 2^31", self.symbol))
+		}
+		if let Some(id) = &self.coingecko_id {
+			let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-';
+			if id.is_empty() || !id.chars().all(valid_char) {
+				return Err(format!(
+					"coingecko id `{}` of token {} must be lowercase letters, digits and dashes",
+					id, self.symbol
+				))
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A named denomination of a token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DenominationType {
+	/// Name of the denomination.
+	pub name: String,
+	/// Power of ten of planck making up one of this denomination, i.e. one of it is
+	/// `10^decimals` planck.
+	pub decimals: u8,
+}
+
+/// A token used by some networks of the registry.
+#[derive(Debug)]
+pub struct TokenType {
+	/// Symbol of the token.
+	pub symbol: String,
+	/// Decimals of the token.
+	pub decimals: u8,
+	/// Display names of the networks using the token.
+	pub networks: Vec<String>,
+	/// Additional metadata of the token, empty apart from the symbol if none is declared.
+	pub metadata: TokenMetadata,
+}
+
+impl TokenType {
+	/// Description of the token for documentation.
+	pub fn doc_string(&self) -> String {
+		format!("{} token used on {}", self.symbol, self.networks.join(", "))
+	}
+}
@@ -19,17 +19,54 @@
 //! Enumerations of well-known SS58 account types and tokens used in the Polkadot ecosystem.
 use core::convert::TryFrom;
 mod address_format;
+#[cfg(feature = "ss58")]
+mod caip;
+#[cfg(feature = "ss58")]
+mod derive;
+#[cfg(feature = "json")]
+pub mod diff;
 mod error;
+#[cfg(feature = "ethereum")]
+mod ethereum;
+#[cfg(any(feature = "json", feature = "ss58"))]
+mod hex_decode;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "ss58")]
+mod multisig;
 mod registry;
+#[cfg(feature = "ss58")]
+mod ss58;
 #[cfg(test)]
 mod tests;
 mod token;
+#[cfg(feature = "ss58")]
+mod uri;
 
 pub use address_format::{from_address_format, Ss58AddressFormat};
-pub use error::ParseError;
-pub use registry::{from_known_address_format, Ss58AddressFormatRegistry, TokenRegistry};
-pub use token::{Token, TokenAmount};
+#[cfg(feature = "ss58")]
+pub use caip::{Caip10AccountId, Caip2ChainId};
+#[cfg(feature = "ss58")]
+pub use derive::DerivedAccount;
+#[cfg(feature = "ethereum")]
+pub use error::EthereumError;
+#[cfg(feature = "ss58")]
+pub use error::{AddressError, CaipError, MultisigError, UriError};
+pub use error::{ParseAmountError, ParseError};
+#[cfg(feature = "ethereum")]
+pub use ethereum::H160;
+#[cfg(feature = "ss58")]
+pub use multisig::multisig_account;
+pub use registry::{
+	from_known_address_format, AssetKind, ExplorerKind, NetworkStatus, NetworkToken, SignatureType,
+	Ss58AddressFormatRegistry, TokenInfo, TokenRegistry,
+};
+pub use token::{CompactAmount, CompactSuffixes, Denomination, Token, TokenAmount, TokenDelta};
+#[cfg(feature = "std")]
+pub use token::{DenominatedAmount, OwnedToken};
+#[cfg(feature = "ss58")]
+pub use uri::SubstrateUri;
 
 #[cfg(feature = "std")]
 use registry::PREFIX_TO_INDEX;
-use registry::{ALL_SS58_ADDRESS_FORMATS, ALL_SS58_ADDRESS_FORMAT_NAMES};
+use registry::{ALL_SS58_ADDRESS_FORMATS, ALL_SS58_ADDRESS_FORMAT_NAMES, ASSIGNED_PREFIX_RANGES};
@@ -0,0 +1,99 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Accounts of the Multisig pallet, derived from the signatories and the threshold.
+
+use super::*;
+use crate::ss58::blake2_256;
+
+/// Prefix of the hashed data of accounts derived by the Multisig and Utility pallets.
+pub(crate) const UTILITY_PREFIX: &[u8] = b"modlpy/utilisuba";
+
+/// SCALE compact encoding of a length.
+fn compact_len(len: usize) -> Vec<u8> {
+	match len as u64 {
+		len @ 0..=0x3f => vec![(len as u8) << 2],
+		len @ 0x40..=0x3fff => ((len as u16) << 2 | 0b01).to_le_bytes().to_vec(),
+		len @ 0x4000..=0x3fff_ffff => ((len as u32) << 2 | 0b10).to_le_bytes().to_vec(),
+		len => {
+			let bytes = len.to_le_bytes();
+			let used = 8 - len.leading_zeros() as usize / 8;
+			let mut encoded = vec![(((used - 4) as u8) << 2) | 0b11];
+			encoded.extend_from_slice(&bytes[..used]);
+			encoded
+		},
+	}
+}
+
+/// The account of the Multisig pallet for the signatories, in any order, and the threshold.
+///
+/// The pallet rejects duplicate signatories, fewer than two of them and thresholds of zero or
+/// above their number, so these are errors here too.
+pub fn multisig_account(
+	signatories: &[[u8; 32]],
+	threshold: u16,
+) -> Result<[u8; 32], MultisigError> {
+	if signatories.len() < 2 {
+		return Err(MultisigError::TooFewSignatories)
+	}
+	if threshold == 0 || usize::from(threshold) > signatories.len() {
+		return Err(MultisigError::InvalidThreshold)
+	}
+	let mut sorted = signatories.to_vec();
+	sorted.sort_unstable();
+	if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+		return Err(MultisigError::DuplicateSignatory)
+	}
+	let signatories: Vec<u8> = sorted.concat();
+	Ok(blake2_256(&[
+		UTILITY_PREFIX,
+		&compact_len(sorted.len()),
+		&signatories,
+		&threshold.to_le_bytes(),
+	]))
+}
+
+impl Ss58AddressFormat {
+	/// The multisig address in this format for the signatories, given as SS58 addresses of any
+	/// format, and the threshold.
+	///
+	/// ```
+	/// # use ss58_registry::{Ss58AddressFormat, Ss58AddressFormatRegistry};
+	/// let format = Ss58AddressFormat::from(Ss58AddressFormatRegistry::SubstrateAccount);
+	/// let signatories = [
+	///     "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+	///     "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+	///     "5FLSigC9HGRKVhB9FiEo4Y3koPsNmBmLJbpXg2mp1hXcS59Y",
+	/// ];
+	/// assert_eq!(
+	///     format.multisig_address(&signatories, 2).unwrap(),
+	///     "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7",
+	/// );
+	/// ```
+	pub fn multisig_address(
+		&self,
+		signatories: &[&str],
+		threshold: u16,
+	) -> Result<String, MultisigError> {
+		let signatories = signatories
+			.iter()
+			.map(|address| {
+				let (_, account) = Ss58AddressFormat::decode(address)?;
+				account.try_into().map_err(|_| MultisigError::InvalidAccount)
+			})
+			.collect::<Result<Vec<[u8; 32]>, _>>()?;
+		Ok(self.encode(&multisig_account(&signatories, threshold)?)?)
+	}
+}
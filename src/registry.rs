@@ -42,11 +42,194 @@ impl TryFrom<Ss58AddressFormat> for Ss58AddressFormatRegistry {
 	}
 }
 
+/// Lifecycle status of a network.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum NetworkStatus {
+	/// A production network.
+	Mainnet,
+	/// A test network.
+	Testnet,
+	/// A network that was shut down or replaced.
+	Deprecated,
+	/// The prefix is reserved for future use.
+	Reserved,
+}
+
+/// Signing curve of the standard account of a network.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum SignatureType {
+	/// Schnorr signatures on Ristretto25519.
+	Sr25519,
+	/// Ed25519 signatures.
+	Ed25519,
+	/// ECDSA signatures on secp256k1, with 33 byte compressed public keys as accounts.
+	Secp256k1,
+	/// Either of the 25519 curves.
+	Any25519,
+}
+
+/// Kind of page of a block explorer.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ExplorerKind {
+	/// An account, given by its address.
+	Address,
+	/// A block, given by its number or hash.
+	Block,
+	/// An extrinsic, given by its hash.
+	Extrinsic,
+}
+
+impl ExplorerKind {
+	/// The placeholder for the value in explorer link templates.
+	pub fn placeholder(&self) -> &'static str {
+		match self {
+			ExplorerKind::Address => "{address}",
+			ExplorerKind::Block => "{block}",
+			ExplorerKind::Extrinsic => "{extrinsic}",
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl Ss58AddressFormatRegistry {
+	/// Link to the block explorer page of the given kind for `value`, if the network has one.
+	/// The value is inserted as is, so it should be URL safe like addresses and hashes are.
+	///
+	/// ```
+	/// # use ss58_registry::{ExplorerKind, Ss58AddressFormatRegistry};
+	/// let polkadot = Ss58AddressFormatRegistry::PolkadotAccount;
+	/// let address = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+	/// let url = polkadot.explorer_url(ExplorerKind::Address, address).unwrap();
+	/// assert_eq!(url, format!("https://polkadot.subscan.io/account/{}", address));
+	/// ```
+	pub fn explorer_url(&self, kind: ExplorerKind, value: &str) -> Option<String> {
+		self.explorer_template(kind)
+			.map(|template| template.replace(kind.placeholder(), value))
+	}
+}
+
+impl Ss58AddressFormatRegistry {
+	/// The native token of the network, held in the default instance of the Balances pallet.
+	/// `None` for reserved and generic formats that have no tokens.
+	///
+	/// ```
+	/// # use ss58_registry::{Ss58AddressFormatRegistry, Token, TokenRegistry};
+	/// let kusama = Ss58AddressFormatRegistry::KusamaAccount.native_token().unwrap();
+	/// assert_eq!(kusama.token(), Some(TokenRegistry::Ksm));
+	/// assert_eq!(Ss58AddressFormatRegistry::SubstrateAccount.native_token(), None);
+	/// ```
+	pub fn native_token(&self) -> Option<NetworkToken> {
+		self.token_for_balances_instance(0)
+	}
+
+	/// The token held in the given instance of the Balances pallet, where 0 is the default
+	/// instance and `n` is `Instance{n}` for the others, in the order of the network's symbols.
+	pub fn token_for_balances_instance(&self, n: u8) -> Option<NetworkToken> {
+		self.network_tokens().get(usize::from(n)).copied()
+	}
+
+	/// Whether the standard account of the network is secp256k1, with Ethereum style 20 byte
+	/// accounts derived from the public keys.
+	pub fn is_ethereum(&self) -> bool {
+		self.standard_account() == Some(SignatureType::Secp256k1)
+	}
+
+	/// Whether the network is a test network.
+	pub fn is_testnet(&self) -> bool {
+		self.status() == NetworkStatus::Testnet
+	}
+
+	/// Whether the network was shut down or replaced.
+	pub fn is_deprecated(&self) -> bool {
+		self.status() == NetworkStatus::Deprecated
+	}
+
+	/// The network of the chain with the given genesis hash, if known.
+	///
+	/// ```
+	/// # use ss58_registry::Ss58AddressFormatRegistry;
+	/// let network = Ss58AddressFormatRegistry::KusamaAccount;
+	/// let genesis = network.genesis_hashes()[0];
+	/// assert_eq!(Ss58AddressFormatRegistry::lookup_by_genesis(&genesis), Some(network));
+	/// ```
+	pub fn lookup_by_genesis(hash: &[u8; 32]) -> Option<Ss58AddressFormatRegistry> {
+		GENESIS_TO_FORMAT
+			.binary_search_by_key(hash, |(genesis, _)| *genesis)
+			.ok()
+			.map(|lookup| GENESIS_TO_FORMAT[lookup].1)
+	}
+}
+
 /// const function to convert [`Ss58AddressFormat`] to u16
 pub const fn from_known_address_format(x: Ss58AddressFormatRegistry) -> u16 {
 	x as u16
 }
 
+/// A token as used on a specific network.
+///
+/// Unlike [`TokenRegistry`], which has a variant per symbol, this also covers scoped symbols,
+/// which networks may use with different decimals.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct NetworkToken {
+	/// The network using the token.
+	pub network: Ss58AddressFormatRegistry,
+	/// Index of the Balances pallet instance of the token on the network.
+	pub index: u8,
+	/// Symbol of the token.
+	pub symbol: &'static str,
+	/// Decimals of the token.
+	pub decimals: u8,
+}
+
+impl NetworkToken {
+	/// The [`TokenRegistry`] variant of the token, unless networks disagree on its decimals.
+	pub fn token(&self) -> Option<TokenRegistry> {
+		TokenRegistry::from_symbol(self.symbol)
+	}
+}
+
+impl From<NetworkToken> for Token {
+	fn from(x: NetworkToken) -> Self {
+		Token { name: x.symbol, decimals: x.decimals }
+	}
+}
+
+/// Kind of asset a token is.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum AssetKind {
+	/// The native token of the chain, held in the Balances pallet.
+	Native,
+	/// An asset of the Assets pallet with the given id.
+	Asset(u32),
+}
+
+/// Metadata of a token declared in the registry, see [`TokenRegistry::info`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TokenInfo {
+	/// Symbol of the token.
+	pub symbol: &'static str,
+	/// Full name of the token, e.g. "Polkadot".
+	pub name: Option<&'static str>,
+	/// Decimals of the token.
+	pub decimals: u8,
+	/// Whether the token is native or an asset.
+	pub asset: AssetKind,
+	/// Minimum balance of an account in planck.
+	pub existential_deposit: Option<u128>,
+	/// SLIP-0044 coin type.
+	pub slip44: Option<u32>,
+	/// CoinGecko API id.
+	pub coingecko_id: Option<&'static str>,
+}
+
+impl TokenInfo {
+	/// The existential deposit as an amount of the token.
+	pub fn existential_deposit_amount(&self) -> Option<TokenAmount> {
+		let token = Token { name: self.symbol, decimals: self.decimals };
+		self.existential_deposit.map(|deposit| token.amount(deposit))
+	}
+}
+
 impl core::fmt::Debug for TokenRegistry {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		let token: Token = (*self).into();
@@ -0,0 +1,203 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding and decoding of SS58 addresses.
+
+use super::*;
+use crate::address_format::MAX_PREFIX;
+use blake2::{digest::consts::U32, Blake2b, Blake2b512, Digest};
+use core::ops::RangeInclusive;
+
+const PREFIX: &[u8] = b"SS58PRE";
+const CHECKSUM_LEN: usize = 2;
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Lengths of account data supported in an SS58 address: public keys (and hashes thereof) of
+/// 25519 curves and compressed secp256k1 public keys.
+const ACCOUNT_LENS: [usize; 2] = [32, 33];
+
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+	let hash = Blake2b512::new().chain_update(PREFIX).chain_update(data).finalize();
+	[hash[0], hash[1]]
+}
+
+/// The 256 bit blake2b hash of the concatenated parts, as used to derive account ids.
+pub(crate) fn blake2_256(parts: &[&[u8]]) -> [u8; 32] {
+	let hash = parts
+		.iter()
+		.fold(Blake2b::<U32>::new(), |hasher, part| hasher.chain_update(part));
+	hash.finalize().into()
+}
+
+/// The one or two byte SS58 encoding of the prefix.
+pub(crate) fn prefix_bytes(prefix: u16) -> Result<Vec<u8>, AddressError> {
+	match prefix {
+		0..=63 => Ok(vec![prefix as u8]),
+		64..=MAX_PREFIX => {
+			// upper six bits of the lower byte(!)
+			let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+			// lower two bits of the lower byte in the high pos,
+			// lower bits of the upper byte in the low pos
+			let second = ((prefix >> 8) as u8) | (((prefix & 0b0000_0000_0000_0011) as u8) << 6);
+			Ok(vec![first | 0b0100_0000, second])
+		},
+		_ => Err(AddressError::InvalidPrefix),
+	}
+}
+
+/// Position of each character of `s` in the base58 alphabet, or `None` if `s` is not base58.
+fn digits(s: &str) -> Option<Vec<usize>> {
+	s.bytes().map(|c| ALPHABET.iter().position(|a| *a == c)).collect()
+}
+
+/// Whether an address of a 32-byte account with the prefix can start with `start`.
+fn can_start_with(prefix: u16, start: &str) -> Result<bool, AddressError> {
+	let mut smallest = prefix_bytes(prefix)?;
+	let start = match digits(start) {
+		Some(start) => start,
+		None => return Ok(false),
+	};
+	if smallest[0] == 0 {
+		// The zero byte is encoded as `1`, and the account alone can be any number.
+		return Ok(start.is_empty() || start[0] == 0)
+	}
+	let mut largest = smallest.clone();
+	smallest.resize(smallest.len() + 32 + CHECKSUM_LEN, 0);
+	largest.resize(smallest.len(), 0xff);
+	let smallest = digits(&bs58::encode(smallest).into_string()).expect("bs58 output");
+	let largest = digits(&bs58::encode(largest).into_string()).expect("bs58 output");
+	// The addresses cover a contiguous range of numbers, which can span two lengths of encoding
+	// (but not more as the largest is less than twice the smallest). Encodings of the same
+	// length compare like the numbers, so check the range of each length.
+	let mut bounds = vec![];
+	if smallest.len() == largest.len() {
+		bounds.push((smallest, largest));
+	} else {
+		let mut first_longer = vec![0; largest.len()];
+		first_longer[0] = 1;
+		bounds.push((smallest.clone(), vec![ALPHABET.len() - 1; smallest.len()]));
+		bounds.push((first_longer, largest));
+	}
+	Ok(bounds.iter().any(|(low, high)| {
+		start.len() <= low.len() &&
+			start[..] >= low[..start.len()] &&
+			start[..] <= high[..start.len()]
+	}))
+}
+
+impl Ss58AddressFormat {
+	/// Characters the addresses of 32-byte accounts of this format can start with, in base58
+	/// order. Empty if the prefix cannot be encoded.
+	///
+	/// ```
+	/// # use ss58_registry::{Ss58AddressFormat, Ss58AddressFormatRegistry};
+	/// let kusama = Ss58AddressFormat::from(Ss58AddressFormatRegistry::KusamaAccount);
+	/// assert_eq!(kusama.leading_chars(), ['C', 'D', 'E', 'F', 'G', 'H', 'J']);
+	/// ```
+	pub fn leading_chars(&self) -> Vec<char> {
+		ALPHABET
+			.iter()
+			.map(|c| char::from(*c))
+			.filter(|c| {
+				matches!(can_start_with(self.prefix(), c.encode_utf8(&mut [0; 4])), Ok(true))
+			})
+			.collect()
+	}
+
+	/// Prefixes within `range` for which addresses of 32-byte accounts can start with `start`.
+	///
+	/// ```
+	/// # use ss58_registry::Ss58AddressFormat;
+	/// let range = Ss58AddressFormat::ONE_BYTE_PREFIXES;
+	/// let prefixes: Vec<_> = Ss58AddressFormat::prefixes_starting_with(range, "5G").collect();
+	/// assert_eq!(prefixes, [42]);
+	/// ```
+	pub fn prefixes_starting_with(
+		range: RangeInclusive<u16>,
+		start: &str,
+	) -> impl Iterator<Item = u16> + '_ {
+		let end = (*range.end()).min(MAX_PREFIX);
+		(*range.start()..=end)
+			.filter(move |prefix| matches!(can_start_with(*prefix, start), Ok(true)))
+	}
+
+	/// Prefixes within `range` that no known address format uses and for which addresses of
+	/// 32-byte accounts can start with `start`.
+	///
+	/// ```
+	/// # use ss58_registry::Ss58AddressFormat;
+	/// let range = Ss58AddressFormat::TWO_BYTE_PREFIXES;
+	/// let free: Vec<_> = Ss58AddressFormat::free_prefixes_starting_with(range, "x").collect();
+	/// assert!(!free.is_empty());
+	/// ```
+	pub fn free_prefixes_starting_with(
+		range: RangeInclusive<u16>,
+		start: &str,
+	) -> impl Iterator<Item = u16> + '_ {
+		Self::prefixes_starting_with(range, start)
+			.filter(|prefix| Ss58AddressFormat::custom(*prefix).is_custom())
+	}
+
+	/// Encodes the account as an SS58 address of this format.
+	///
+	/// ```
+	/// # use ss58_registry::{Ss58AddressFormat, Ss58AddressFormatRegistry};
+	/// let address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	/// let (format, alice) = Ss58AddressFormat::decode(address).unwrap();
+	/// assert_eq!(format, Ss58AddressFormatRegistry::SubstrateAccount.into());
+	///
+	/// let kusama: Ss58AddressFormat = Ss58AddressFormatRegistry::KusamaAccount.into();
+	/// assert_eq!(kusama.encode(&alice).unwrap(), "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F");
+	/// ```
+	pub fn encode(&self, account: &[u8]) -> Result<String, AddressError> {
+		if !ACCOUNT_LENS.contains(&account.len()) {
+			return Err(AddressError::BadLength)
+		}
+		let mut data = prefix_bytes(self.prefix())?;
+		data.extend_from_slice(account);
+		let checksum = checksum(&data);
+		data.extend_from_slice(&checksum);
+		Ok(bs58::encode(data).into_string())
+	}
+
+	/// Decodes an SS58 address into its format and account data.
+	pub fn decode(address: &str) -> Result<(Ss58AddressFormat, Vec<u8>), AddressError> {
+		let data = bs58::decode(address).into_vec().map_err(|_| AddressError::BadBase58)?;
+		let (prefix_len, prefix) = match data.first().copied() {
+			Some(0..=63) => (1, u16::from(data[0])),
+			Some(64..=127) if data.len() > 1 => {
+				// weird bit manipulation owing to the combination of LE encoding and missing two
+				// bits from the left.
+				// d[0] d[1] are: 01aaaaaa bbcccccc
+				// they make the LE-encoded 16-bit value: aaaaaabb 00cccccc
+				// so the lower byte is formed of aaaaaabb and the higher byte is 00cccccc
+				let lower = (data[0] << 2) | (data[1] >> 6);
+				let upper = data[1] & 0b0011_1111;
+				(2, u16::from(lower) | (u16::from(upper) << 8))
+			},
+			Some(_) => return Err(AddressError::InvalidPrefix),
+			None => return Err(AddressError::BadLength),
+		};
+		let account_len = data.len().checked_sub(prefix_len + CHECKSUM_LEN);
+		if !matches!(account_len, Some(len) if ACCOUNT_LENS.contains(&len)) {
+			return Err(AddressError::BadLength)
+		}
+		let (payload, expected) = data.split_at(data.len() - CHECKSUM_LEN);
+		if checksum(payload) != expected {
+			return Err(AddressError::InvalidChecksum)
+		}
+		Ok((Ss58AddressFormat::custom(prefix), payload[prefix_len..].to_vec()))
+	}
+}
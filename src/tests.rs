@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Ss58AddressFormat, Ss58AddressFormatRegistry, TokenRegistry};
+use super::{NetworkStatus, Ss58AddressFormat, Ss58AddressFormatRegistry, Token, TokenRegistry};
 
 #[test]
 fn is_reserved() {
@@ -71,3 +71,1008 @@ fn tokens() {
 	let n46 = Ss58AddressFormatRegistry::Reserved46Account;
 	assert_eq!(n46.tokens(), &[]);
 }
+
+#[test]
+fn network_tokens() {
+	use super::NetworkToken;
+
+	let polka = Ss58AddressFormatRegistry::PolkadotAccount;
+	let dot = NetworkToken { network: polka, index: 0, symbol: "DOT", decimals: 10 };
+	assert_eq!(polka.network_tokens(), &[dot]);
+	assert_eq!(dot.token(), Some(TokenRegistry::Dot));
+	assert_eq!(Token::from(dot), TokenRegistry::Dot.into());
+	let kusama = Ss58AddressFormatRegistry::KusamaAccount;
+	assert_eq!(kusama.network_tokens()[0].token(), Some(TokenRegistry::Ksm));
+	let n46 = Ss58AddressFormatRegistry::Reserved46Account;
+	assert_eq!(n46.network_tokens(), &[]);
+
+	assert_eq!(TokenRegistry::from_symbol("KSM"), Some(TokenRegistry::Ksm));
+	assert_eq!(TokenRegistry::from_symbol("ksm"), None);
+	for network in Ss58AddressFormat::all() {
+		let global = network.network_tokens().iter().filter_map(|t| t.token());
+		assert!(network.tokens().iter().copied().eq(global));
+		for (i, token) in network.network_tokens().iter().enumerate() {
+			assert_eq!((token.network, usize::from(token.index)), (*network, i));
+			if let Some(global) = token.token() {
+				assert_eq!(Token::from(global), Token::from(*token));
+			}
+		}
+	}
+}
+
+#[test]
+fn denominations() {
+	use super::Denomination;
+
+	let ksm: Token = TokenRegistry::Ksm.into();
+	let point = Denomination::Custom { name: "Point", decimals: 9 };
+	assert_eq!(TokenRegistry::Ksm.denominations(), &[point]);
+	assert_eq!(TokenRegistry::Dot.denominations(), &[]);
+
+	let amount = ksm.units(1.5);
+	assert_eq!(amount.as_planck(), 1_500_000_000_000);
+	assert_eq!(amount.as_milli(), 1500.0);
+	assert_eq!(amount.as_micro(), 1_500_000.0);
+	assert_eq!(amount.as_denomination(point), 1500.0);
+	assert_eq!(ksm.denominated(1500.0, point).amount, amount.amount);
+
+	let g1 = Token { name: "G1", decimals: 2 };
+	assert_eq!(g1.micro(10_000.0).amount, 1);
+	assert_eq!(g1.units(-1.0).amount, 0);
+	// Amounts beyond `i128::MAX` are still rounded to the nearest planck.
+	let g0 = Token { name: "G0", decimals: 0 };
+	assert_eq!(g0.units(3e38).amount, 3e38 as u128);
+	assert!(g0.units(3e38).amount > i128::MAX as u128);
+	assert_eq!(g0.units(1e39).amount, u128::MAX);
+	assert_eq!(g0.units(2.5).amount, 3);
+	assert_eq!(g0.units(0.49999999999999994).amount, 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_amounts() {
+	use super::CompactSuffixes;
+
+	let dot: Token = TokenRegistry::Dot.into();
+	assert_eq!(dot.amount(0).compact().to_string(), "0 DOT");
+	assert_eq!(dot.units(999_999.0).compact().to_string(), "999K DOT");
+	assert_eq!(dot.units(12_000_000.0).compact().to_string(), "12M DOT");
+	assert_eq!(dot.units(1e15).compact().to_string(), "1000T DOT");
+	assert_eq!(dot.units(1e15).compact().suffixes(CompactSuffixes::Si).to_string(), "1P DOT");
+	assert_eq!(dot.units(1_234.0).compact().significant_digits(0).to_string(), "1K DOT");
+
+	let g1 = Token { name: "G1", decimals: 2 };
+	assert_eq!(g1.amount(5).compact().to_string(), "0.05 G1");
+	assert_eq!(g1.amount(123_456).compact().to_string(), "1.23K G1");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_tokens() {
+	use std::borrow::Cow;
+
+	let dot: Token = TokenRegistry::Dot.into();
+	let owned = dot.clone().into_owned();
+	assert_eq!(owned.as_borrowed(), dot);
+	assert_eq!(owned.units(2.5).to_string(), dot.units(2.5).to_string());
+
+	let asset = Token { name: Cow::Owned(String::from("USDT")), decimals: 6 };
+	assert_eq!(asset.amount(1_234_567).to_string(), "1.234 USDT");
+	assert_eq!(format!("{:?}", asset.amount(1_234_567)), "1.234 USDT (1,234,567)");
+	assert_eq!(asset.units(12_300.0).compact().to_string(), "12.3K USDT");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn token_deltas() {
+	use super::{ParseAmountError, TokenDelta};
+
+	let dot: Token = TokenRegistry::Dot.into();
+	let ksm: Token = TokenRegistry::Ksm.into();
+	let credit = dot.units(1.0);
+	let debit = dot.units(1.25);
+
+	let delta = TokenDelta::from_amounts(credit.clone(), debit.clone()).unwrap();
+	assert_eq!(delta, dot.delta(-2_500_000_000));
+	assert!(delta.is_negative());
+	assert_eq!(delta.magnitude(), dot.units(0.25));
+	assert_eq!(delta.clone().into_amounts(), (dot.amount(0), dot.units(0.25)));
+	assert_eq!(debit.checked_apply(&delta), Some(dot.units(1.0)));
+	assert_eq!(dot.amount(1).checked_apply(&delta), None);
+	assert_eq!(TokenDelta::from_amounts(credit, ksm.units(1.0)), None);
+	assert_eq!(TokenDelta::from_amounts(dot.amount(u128::MAX), dot.amount(0)), None);
+
+	assert_eq!(dot.parse_delta("-1,000.5 DOT"), Ok(dot.delta(-10_005_000_000_000)));
+	assert_eq!(dot.parse_delta(" 0.1"), Ok(dot.delta(1_000_000_000)));
+	assert_eq!(dot.parse_amount("-1"), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(dot.parse_amount("1,00"), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(dot.parse_amount("1000,000"), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(dot.parse_amount(",100"), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(dot.parse_amount("12,345,678"), Ok(dot.units(12_345_678.0)));
+	assert_eq!(dot.parse_amount("."), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(dot.parse_amount("1e40"), Err(ParseAmountError::InvalidNumber));
+	assert_eq!(
+		dot.parse_amount("100000000000000000000000000000000"),
+		Err(ParseAmountError::Overflow)
+	);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn token_delta_round_trip() {
+	let ksm: Token = TokenRegistry::Ksm.into();
+	for delta in [-1_234_567_000_000_000, -1, 0, 250_000_000_000] {
+		let delta = ksm.delta(delta);
+		let rendered = delta.to_string();
+		let truncated = delta.delta / 1_000_000_000 * 1_000_000_000;
+		assert_eq!(ksm.parse_delta(&rendered), Ok(ksm.delta(truncated)), "{}", rendered);
+	}
+	assert_eq!(ksm.delta(0).to_string(), "0.000 KSM");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decimal_conversions() {
+	let dot: Token = TokenRegistry::Dot.into();
+	let eighteen = Token { name: "GLMR", decimals: 18 };
+	for amount in [0, 1, 10_000_000_000, 12_345_678_901_234, u128::MAX] {
+		for token in [&dot, &eighteen] {
+			let amount = token.amount(amount);
+			assert_eq!(token.parse_amount(&amount.to_decimal_string()), Ok(amount.clone()));
+			let expected: f64 = amount.to_decimal_string().parse().unwrap();
+			assert_eq!(amount.to_f64_lossy(), expected);
+		}
+	}
+	assert_eq!(dot.amount(10_000_000_000).to_decimal_string(), "1");
+	assert_eq!(dot.amount(1).to_decimal_string(), "0.0000000001");
+	assert_eq!(dot.units(0.1).to_f64_lossy(), 0.1);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn rust_decimal_conversions() {
+	use rust_decimal::Decimal;
+
+	let dot: Token = TokenRegistry::Dot.into();
+	let amount = dot.amount(12_345_000_000_001);
+	let decimal = amount.to_decimal().unwrap();
+	assert_eq!(decimal, Decimal::from_i128_with_scale(12_345_000_000_001, 10));
+	assert_eq!(dot.amount_from_decimal(decimal), Some(amount));
+
+	let eighteen = Token { name: "GLMR", decimals: 18 };
+	let large = eighteen.amount(10_u128.pow(30));
+	assert_eq!(large.to_decimal(), Some(Decimal::from(1_000_000_000_000_u64)));
+	assert_eq!(eighteen.amount(10_u128.pow(30) + 1).to_decimal(), None);
+
+	assert_eq!(dot.amount_from_decimal(Decimal::new(-1, 0)), None);
+	assert_eq!(dot.amount_from_decimal(Decimal::new(1, 11)), None);
+	assert_eq!(dot.amount_from_decimal(Decimal::new(1_500, 3)), Some(dot.units(1.5)));
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn ss58_encode_decode() {
+	use super::AddressError;
+
+	let alice = [
+		0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f,
+		0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
+		0xa2, 0x7d,
+	];
+	for (network, address) in [
+		(
+			Ss58AddressFormatRegistry::PolkadotAccount,
+			"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+		),
+		(
+			Ss58AddressFormatRegistry::KusamaAccount,
+			"HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F",
+		),
+		(
+			Ss58AddressFormatRegistry::MoonbeamAccount,
+			"VdvKmYJfD4VXA9fzz1SbmCo2eYHSzUFbaDCZSuaNKJAe8YNg6",
+		),
+	] {
+		let format: Ss58AddressFormat = network.into();
+		assert_eq!(format.encode(&alice).as_deref(), Ok(address));
+		assert_eq!(Ss58AddressFormat::decode(address), Ok((format, alice.to_vec())));
+	}
+	for prefix in [0, 63, 64, 16383] {
+		let format = Ss58AddressFormat::custom(prefix);
+		let address = format.encode(&[prefix as u8; 33]).unwrap();
+		assert_eq!(Ss58AddressFormat::decode(&address), Ok((format, vec![prefix as u8; 33])));
+	}
+
+	assert_eq!(Ss58AddressFormat::custom(16384).encode(&alice), Err(AddressError::InvalidPrefix));
+	assert_eq!(Ss58AddressFormat::custom(0).encode(&alice[..20]), Err(AddressError::BadLength));
+	assert_eq!(
+		Ss58AddressFormat::decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6"),
+		Err(AddressError::InvalidChecksum)
+	);
+	assert_eq!(Ss58AddressFormat::decode("0OIl"), Err(AddressError::BadBase58));
+	assert_eq!(Ss58AddressFormat::decode(""), Err(AddressError::BadLength));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_json() {
+	use super::json::Registry;
+
+	let mut registry = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	registry.validate().unwrap();
+	assert_eq!(registry.lowest_free_prefix(), Some(59));
+
+	let mut proposed = registry.accounts[0].clone();
+	proposed.prefix = 59;
+	registry.accounts.push(proposed);
+	assert!(registry.validate().unwrap_err().starts_with("networks must be unique"));
+	registry.accounts.last_mut().unwrap().network = "polkadot-copy".into();
+	assert!(registry.validate().unwrap_err().contains("is already used by polkadot"));
+	registry.accounts.last_mut().unwrap().genesis_hashes = vec!["0x1234".into()];
+	assert!(registry.validate().unwrap_err().starts_with("invalid genesis hash `0x1234`"));
+	registry.accounts.last_mut().unwrap().genesis_hashes.clear();
+	registry.validate().unwrap();
+
+	registry.sort();
+	let json = registry.to_json();
+	assert!(json.contains("\"symbols\": [\"DOT\"],\n"));
+	let reparsed = Registry::from_json(&json).unwrap();
+	assert_eq!(reparsed.accounts[59].network, "polkadot-copy");
+	assert_eq!(reparsed.to_json(), json);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_format() {
+	use super::json;
+
+	let messy = r#"{"specification": "spec", "schema": {"prefix": "", "network": "", "displayName": "",
+		"symbols": "", "decimals": "", "standardAccount": "", "website": ""},
+		"registry": [
+			{"network": "b", "prefix": 1, "symbols": ["B"], "decimals": [10],
+				"displayName": "B", "standardAccount": "Sr25519", "website": null},
+			{"displayName": "A", "prefix": 0, "network": "a", "symbols": [], "decimals": [],
+				"standardAccount": null, "website": null}
+		]}"#;
+	let formatted = json::format(messy).unwrap();
+	assert_eq!(json::format(&formatted).unwrap(), formatted);
+	let a = formatted.find("\"network\": \"a\"").unwrap();
+	assert!(a < formatted.find("\"network\": \"b\"").unwrap());
+	assert!(formatted[..a].ends_with("\"prefix\": 0,\n      "));
+	assert!(formatted.contains("\"symbols\": [\"B\"],\n"));
+
+	let unknown = messy.replace("\"website\": null}", "\"website\": null, \"foo\": 1}");
+	assert!(json::format(&unknown).unwrap_err().contains("unknown field `foo`"));
+	let unknown = messy.replace("\"specification\"", "\"foo\": 1, \"specification\"");
+	assert!(json::format(&unknown).unwrap_err().contains("unknown field `foo`"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_diff() {
+	use super::{
+		diff,
+		json::{DenominationType, Registry},
+	};
+
+	let old = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	assert_eq!(diff::diff(&old, &old), Default::default());
+
+	let mut new = old.clone();
+	new.accounts.retain(|a| a.network != "xcavate");
+	let kusama = new.accounts.iter_mut().find(|a| a.network == "kusama").unwrap();
+	kusama.decimals = vec![10];
+	kusama.website = None;
+	let mut added = kusama.clone();
+	added.prefix = 59;
+	added.network = "new-net".into();
+	added.symbols = vec!["NEW".into()];
+	new.accounts.push(added);
+
+	let diff = diff::diff(&old, &new);
+	assert!(diff.is_breaking());
+	let descriptions: Vec<_> =
+		diff.changes.iter().map(|c| (c.is_breaking(), c.to_string())).collect();
+	assert_eq!(
+		descriptions,
+		[
+			(true, "Changed decimals of `KSM` on `kusama` from 12 to 10".to_owned()),
+			(false, "Removed website of `kusama`".to_owned()),
+			(false, "Added network `new-net` with prefix 59 (`NewNetAccount`)".to_owned()),
+			(true, "Removed network `xcavate` with prefix 8888 (`XcavateAccount`)".to_owned()),
+			(true, "Removed token `XCAV`".to_owned()),
+			(false, "Added token `NEW`".to_owned()),
+		]
+	);
+	assert!(diff.to_markdown().starts_with(
+		"### Breaking changes\n\n- Changed decimals of `KSM` on `kusama` from 12 to 10\n"
+	));
+
+	let mut new = old.clone();
+	let dot = new.tokens.iter_mut().find(|t| t.symbol == "DOT").unwrap();
+	dot.existential_deposit = Some("1".into());
+	dot.coingecko_id = None;
+	dot.denominations = vec![DenominationType { name: "Tick".into(), decimals: 5 }];
+	let ksm = new.tokens.iter_mut().find(|t| t.symbol == "KSM").unwrap();
+	ksm.denominations[0].name = "Pt".into();
+	let descriptions: Vec<_> = diff::diff(&old, &new)
+		.changes
+		.iter()
+		.map(|c| (c.is_breaking(), c.to_string()))
+		.collect();
+	assert_eq!(
+		descriptions,
+		[
+			(false, "Changed existentialDeposit of `DOT` from `10000000000` to `1`".to_owned()),
+			(false, "Removed coingeckoId of `DOT`".to_owned()),
+			(false, "Added denomination `Tick` of `DOT` with 5 decimals".to_owned()),
+			(true, "Renamed denomination `Point` of `KSM` to `Pt`".to_owned()),
+		]
+	);
+	new.tokens.retain(|t| t.symbol != "KSM");
+	let descriptions: Vec<_> =
+		diff::diff(&old, &new).changes.iter().map(|c| c.to_string()).collect();
+	assert!(descriptions.contains(&"Removed name of `KSM`".to_owned()));
+	assert!(descriptions.contains(&"Removed denomination `Point` of `KSM`".to_owned()));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn semver_guard() {
+	use super::{
+		diff::{self, Bump, Version},
+		json::Registry,
+	};
+
+	let v = |s: &str| s.parse::<Version>().unwrap();
+	assert_eq!(
+		Version::from_manifest(include_str!("../Cargo.toml")).unwrap(),
+		v(env!("CARGO_PKG_VERSION"))
+	);
+	assert!("1.2".parse::<Version>().is_err());
+
+	let old = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let unchanged = diff::diff(&old, &old);
+	assert_eq!(unchanged.required_bump(), Bump::None);
+	assert!(diff::check_version_bump(&unchanged, &v("1.51.0"), &v("1.51.1")).is_ok());
+	assert!(diff::check_version_bump(&unchanged, &v("1.51.0"), &v("1.50.0")).is_err());
+
+	let mut added = old.clone();
+	let mut network = added.accounts[0].clone();
+	network.prefix = 59;
+	network.network = "new-net".into();
+	added.accounts.push(network);
+	let additive = diff::diff(&old, &added);
+	assert_eq!(additive.required_bump(), Bump::Minor);
+	let err = diff::check_version_bump(&additive, &v("1.51.0"), &v("1.51.1")).unwrap_err();
+	assert!(err.ends_with(
+		"minor version bump:\n- Added network `new-net` with prefix 59 (`NewNetAccount`)"
+	));
+	assert!(diff::check_version_bump(&additive, &v("1.51.0"), &v("1.52.0")).is_ok());
+	assert!(diff::check_version_bump(&additive, &v("0.3.0"), &v("0.3.1")).is_ok());
+
+	let mut data = old.clone();
+	data.accounts.iter_mut().find(|a| a.network == "kusama").unwrap().website = None;
+	let data = diff::diff(&old, &data);
+	assert_eq!(data.required_bump(), Bump::Patch);
+	let err = diff::check_version_bump(&data, &v("1.51.0"), &v("1.51.0")).unwrap_err();
+	assert!(err.ends_with("patch version bump:\n- Removed website of `kusama`"), "{}", err);
+	assert!(diff::check_version_bump(&data, &v("1.51.0"), &v("1.51.1")).is_ok());
+
+	let mut renamed = old.clone();
+	renamed.accounts.iter_mut().find(|a| a.network == "kusama").unwrap().network = "kusama2".into();
+	let breaking = diff::diff(&old, &renamed);
+	assert_eq!(breaking.required_bump(), Bump::Major);
+	let err = diff::check_version_bump(&breaking, &v("1.51.0"), &v("1.52.0")).unwrap_err();
+	assert!(err.contains("major version bump"), "{}", err);
+	assert!(diff::check_version_bump(&breaking, &v("1.51.0"), &v("2.0.0")).is_ok());
+	assert!(diff::check_version_bump(&breaking, &v("0.3.0"), &v("0.4.0")).is_ok());
+
+	// Renames that keep the generated variant names do not break the enums, although the
+	// symbols a network reports still change.
+	let mut renamed = old.clone();
+	renamed.accounts.iter_mut().find(|a| a.network == "kusama").unwrap().network = "Kusama".into();
+	let xcavate = renamed.accounts.iter_mut().find(|a| a.network == "xcavate").unwrap();
+	xcavate.symbols = vec!["Xcav".into()];
+	let descriptions: Vec<_> = diff::diff(&old, &renamed)
+		.changes
+		.iter()
+		.map(|c| (c.is_breaking(), c.to_string()))
+		.collect();
+	assert_eq!(
+		descriptions,
+		[
+			(
+				false,
+				"Renamed network `kusama` with prefix 2 to `Kusama` (`KusamaAccount` is unchanged)"
+					.to_owned()
+			),
+			(true, "Changed symbols of `xcavate` from `XCAV` to `Xcav`".to_owned()),
+			(false, "Renamed token `XCAV` to `Xcav` (`Xcav` is unchanged)".to_owned()),
+		]
+	);
+
+	// Distinct symbols becoming the same variant are breaking.
+	let mut colliding = old.clone();
+	colliding.accounts.iter_mut().find(|a| a.network == "watr").unwrap().symbols =
+		vec!["Ksm".into()];
+	let collision = diff::diff(&old, &colliding);
+	assert_eq!(collision.required_bump(), Bump::Major);
+	assert!(collision
+		.changes
+		.iter()
+		.any(|c| c.to_string() == "Tokens `KSM`, `Ksm` would all become `TokenRegistry::Ksm`"));
+	assert!(colliding
+		.validate()
+		.unwrap_err()
+		.contains("tokens KSM and Ksm would both become TokenRegistry::Ksm"));
+}
+
+#[test]
+fn free_prefixes() {
+	let ranges = Ss58AddressFormat::assigned_ranges();
+	assert!(ranges.windows(2).all(|w| w[0].end() + 1 < *w[1].start()));
+	for network in Ss58AddressFormat::all() {
+		let prefix = Ss58AddressFormat::from(*network).prefix();
+		assert!(ranges.iter().any(|run| run.contains(&prefix)));
+	}
+
+	let free = || Ss58AddressFormat::free_prefixes(Ss58AddressFormat::ONE_BYTE_PREFIXES);
+	assert!(free().all(|prefix| Ss58AddressFormat::custom(prefix).is_custom()));
+	assert!(!free().any(|prefix| prefix == 0 || prefix == 42));
+	// Mythos uses the prefix 29972, which does not fit in an address.
+	let encodable = ranges.iter().filter(|run| *run.end() <= 16383);
+	let total = Ss58AddressFormat::free_prefixes(0..=u16::MAX).count() +
+		encodable.map(|run| run.len()).sum::<usize>();
+	assert_eq!(total, 16384);
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn leading_chars() {
+	let leading = |prefix: u16| Ss58AddressFormat::custom(prefix).leading_chars();
+	assert_eq!(leading(0), ['1']);
+	assert_eq!(leading(42), ['5']);
+	assert_eq!(leading(2), ['C', 'D', 'E', 'F', 'G', 'H', 'J']);
+	assert!(leading(16384).is_empty());
+
+	let alice = [
+		0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f,
+		0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
+		0xa2, 0x7d,
+	];
+	for prefix in (0..=16383).step_by(7) {
+		let address = Ss58AddressFormat::custom(prefix).encode(&alice).unwrap();
+		let first = address.chars().next().unwrap();
+		assert!(leading(prefix).contains(&first), "{} for {}", address, prefix);
+		let found: Vec<_> =
+			Ss58AddressFormat::prefixes_starting_with(prefix..=prefix, &address[..3]).collect();
+		assert_eq!(found, [prefix], "{}", address);
+	}
+
+	let two_bytes = Ss58AddressFormat::TWO_BYTE_PREFIXES;
+	let free: Vec<_> =
+		Ss58AddressFormat::free_prefixes_starting_with(two_bytes.clone(), "x").collect();
+	assert!(free.contains(&244) && free.contains(&15347));
+	assert!(free.iter().all(|prefix| Ss58AddressFormat::custom(*prefix).is_custom()));
+	let moonbeam: Vec<_> = Ss58AddressFormat::prefixes_starting_with(two_bytes, "VdvK").collect();
+	assert!(moonbeam.contains(&1284));
+	assert_eq!(Ss58AddressFormat::prefixes_starting_with(0..=63, "0").count(), 0);
+	assert_eq!(Ss58AddressFormat::prefixes_starting_with(0..=63, "11").collect::<Vec<_>>(), [0]);
+	assert_eq!(Ss58AddressFormat::prefixes_starting_with(0..=63, "").count(), 64);
+}
+
+#[test]
+fn genesis_hashes() {
+	let polkadot = Ss58AddressFormatRegistry::PolkadotAccount;
+	let genesis = [
+		0x91, 0xb1, 0x71, 0xbb, 0x15, 0x8e, 0x2d, 0x38, 0x48, 0xfa, 0x23, 0xa9, 0xf1, 0xc2, 0x51,
+		0x82, 0xfb, 0x8e, 0x20, 0x31, 0x3b, 0x2c, 0x1e, 0xb4, 0x92, 0x19, 0xda, 0x7a, 0x70, 0xce,
+		0x90, 0xc3,
+	];
+	assert_eq!(polkadot.genesis_hashes(), [genesis]);
+	assert_eq!(Ss58AddressFormatRegistry::lookup_by_genesis(&genesis), Some(polkadot));
+	assert_eq!(Ss58AddressFormatRegistry::lookup_by_genesis(&[0; 32]), None);
+	assert!(Ss58AddressFormatRegistry::SubstrateAccount.genesis_hashes().is_empty());
+	for network in Ss58AddressFormat::all() {
+		for hash in network.genesis_hashes() {
+			assert_eq!(Ss58AddressFormatRegistry::lookup_by_genesis(hash), Some(*network));
+		}
+	}
+}
+
+#[test]
+fn parachains() {
+	use Ss58AddressFormatRegistry::*;
+
+	assert_eq!(BifrostAccount.relay_of(), Some(KusamaAccount));
+	assert_eq!(BifrostAccount.para_id(), Some(2001));
+	assert_eq!(KusamaAccount.relay_of(), None);
+	assert_eq!(KusamaAccount.para_id(), None);
+	assert_eq!(KusamaAccount.parachains_of(), [KaruraAccount, BifrostAccount, MoonriverAccount]);
+	assert!(BifrostAccount.parachains_of().is_empty());
+	for network in Ss58AddressFormat::all() {
+		for parachain in network.parachains_of() {
+			assert_eq!(parachain.relay_of(), Some(*network));
+		}
+	}
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_parachains() {
+	use super::json::Registry;
+
+	let registry = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let validate = |network: &str, relay_chain: Option<&str>, para_id: Option<u32>| {
+		let mut registry = registry.clone();
+		let account = registry.accounts.iter_mut().find(|a| a.network == network).unwrap();
+		account.relay_chain = relay_chain.map(Into::into);
+		account.para_id = para_id;
+		registry.validate().map(|_| ()).unwrap_err()
+	};
+	assert!(validate("watr", Some("polkadot"), None).starts_with("relayChain and paraId"));
+	assert!(validate("watr", Some("rococo"), Some(2058)).contains("is not in the registry"));
+	assert!(validate("watr", Some("bifrost"), Some(2058)).contains("is a parachain itself"));
+	assert_eq!(
+		validate("watr", Some("polkadot"), Some(2000)),
+		"para id 2000 on polkadot is used by both acala and watr"
+	);
+}
+
+#[test]
+fn network_status() {
+	use Ss58AddressFormatRegistry::*;
+
+	assert_eq!(PolkadotAccount.status(), NetworkStatus::Mainnet);
+	assert_eq!(Reserved46Account.status(), NetworkStatus::Reserved);
+	assert!(CessTestnetAccount.is_testnet());
+	assert!(!KusamaAccount.is_testnet() && !KusamaAccount.is_deprecated());
+	// Prefixes that cannot be encoded, like the one of Mythos, are always reserved.
+	for network in Ss58AddressFormat::all() {
+		let format = Ss58AddressFormat::from(*network);
+		if format.prefix() <= 16383 {
+			assert_eq!(network.status() == NetworkStatus::Reserved, format.is_reserved());
+		}
+	}
+
+	let testnets = || Ss58AddressFormat::all_with_status(&[NetworkStatus::Testnet]);
+	assert!(
+		testnets().any(|n| n == CessTestnetAccount) && !testnets().any(|n| n == PolkadotAccount)
+	);
+	let all = [
+		NetworkStatus::Mainnet,
+		NetworkStatus::Testnet,
+		NetworkStatus::Deprecated,
+		NetworkStatus::Reserved,
+	];
+	assert_eq!(Ss58AddressFormat::all_with_status(&all).count(), Ss58AddressFormat::all().len());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_status() {
+	use super::json::{self, Registry};
+
+	let mut registry = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let polkadot = registry.accounts.iter_mut().find(|a| a.network == "polkadot").unwrap();
+	polkadot.status = Some(json::NetworkStatus::Reserved);
+	assert!(registry.validate().unwrap_err().contains("must be reserved exactly if"));
+
+	let reserved = registry.accounts.iter_mut().find(|a| a.network == "reserved46").unwrap();
+	// Reserved is implied by the null standard account.
+	assert!(reserved.status.is_none());
+	assert_eq!(reserved.effective_status(), json::NetworkStatus::Reserved);
+	reserved.status = Some(json::NetworkStatus::Testnet);
+	assert!(registry.validate().is_err());
+}
+
+#[test]
+fn explorer_links() {
+	use super::ExplorerKind;
+	use Ss58AddressFormatRegistry::*;
+
+	assert_eq!(
+		KusamaAccount.explorer_template(ExplorerKind::Block),
+		Some("https://kusama.subscan.io/block/{block}")
+	);
+	assert_eq!(BareSr25519Account.explorer_template(ExplorerKind::Address), None);
+	for network in Ss58AddressFormat::all() {
+		for kind in [ExplorerKind::Address, ExplorerKind::Block, ExplorerKind::Extrinsic] {
+			if let Some(template) = network.explorer_template(kind) {
+				assert_eq!(template.matches(kind.placeholder()).count(), 1);
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn explorer_urls() {
+	use super::ExplorerKind;
+
+	let polkadot = Ss58AddressFormatRegistry::PolkadotAccount;
+	assert_eq!(
+		polkadot.explorer_url(ExplorerKind::Extrinsic, "0x1234").as_deref(),
+		Some("https://polkadot.subscan.io/extrinsic/0x1234")
+	);
+	assert_eq!(
+		polkadot.explorer_url(ExplorerKind::Block, "1000").as_deref(),
+		Some("https://polkadot.subscan.io/block/1000")
+	);
+	assert_eq!(
+		Ss58AddressFormatRegistry::SubstrateAccount.explorer_url(ExplorerKind::Block, "1"),
+		None
+	);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_explorer() {
+	use super::json::{ExplorerTemplates, Registry};
+
+	let registry = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let validate = |explorer: ExplorerTemplates| {
+		let mut registry = registry.clone();
+		registry.accounts[0].explorer = Some(explorer);
+		registry.validate().map(|_| ())
+	};
+	let address =
+		|address: &str| ExplorerTemplates { address: Some(address.into()), ..Default::default() };
+	assert!(validate(address("https://x.io/account/{address}")).is_ok());
+	assert!(validate(address("ftp://x.io/{address}"))
+		.unwrap_err()
+		.contains("not an http(s) link"));
+	assert!(validate(address("https://x.io/account/{block}"))
+		.unwrap_err()
+		.contains("must contain"));
+	assert!(validate(address("https://x.io/{address}/{address}")).is_err());
+	let json = r#"{ "address": "https://x.io/{address}", "transfer": "https://x.io" }"#;
+	assert!(serde_json::from_str::<ExplorerTemplates>(json).is_err());
+}
+
+#[test]
+fn token_info() {
+	use super::{AssetKind, TokenInfo};
+
+	assert_eq!(
+		TokenRegistry::Dot.info(),
+		TokenInfo {
+			symbol: "DOT",
+			name: Some("Polkadot"),
+			decimals: 10,
+			asset: AssetKind::Native,
+			existential_deposit: Some(10_000_000_000),
+			slip44: Some(354),
+			coingecko_id: Some("polkadot"),
+		}
+	);
+	let ksm = TokenRegistry::Ksm.info();
+	assert_eq!(ksm.existential_deposit_amount().map(|a| a.amount), Some(333_333_333));
+	assert_eq!(TokenRegistry::Ksm.denominations().len(), 1);
+	let aca = TokenRegistry::Aca.info();
+	assert_eq!((aca.symbol, aca.name, aca.existential_deposit), ("ACA", None, None));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_token_metadata() {
+	use super::json::Registry;
+
+	let registry = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let validate = |edit: &dyn Fn(&mut super::json::TokenMetadata)| {
+		let mut registry = registry.clone();
+		edit(&mut registry.tokens[0]);
+		registry.validate().map(|_| ()).unwrap_err()
+	};
+	assert!(validate(&|t| t.existential_deposit = Some("1.5".into())).contains("not an amount"));
+	assert!(validate(&|t| t.coingecko_id = Some("Polkadot".into())).contains("coingecko id"));
+	assert!(validate(&|t| t.slip44 = Some(1 << 31)).contains("slip44"));
+	assert!(validate(&|t| t.name = Some(" ".into())).contains("empty name"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn registry_symbol_collisions() {
+	use super::{diff, json::Registry};
+
+	let old = Registry::from_json(include_str!("../ss58-registry.json")).unwrap();
+	let mut new = old.clone();
+	let watr = new.accounts.iter_mut().find(|a| a.network == "watr").unwrap();
+	watr.symbols = vec!["KSM".into()];
+	watr.decimals = vec![18];
+	// Conflicting decimals must not silently remove the variant.
+	assert!(new.global_symbols().contains("KSM"));
+	assert!(new
+		.validate()
+		.unwrap_err()
+		.starts_with("networks use KSM with different decimals"));
+
+	new.scoped_symbols = vec!["KSM".into()];
+	assert!(!new.global_symbols().contains("KSM"));
+	// Metadata is declared for KSM, which no longer has a variant.
+	assert!(new.validate().unwrap_err().contains("token KSM has metadata"));
+	new.tokens.retain(|t| t.symbol != "KSM");
+	let tokens = new.validate().unwrap();
+	assert!(tokens.iter().all(|t| t.symbol != "KSM"));
+	assert!(new.to_json().contains("\"scopedSymbols\": [\"KSM\"]\n"));
+	new.scoped_symbols.push("KSM".into());
+	assert!(new.validate().unwrap_err().contains("scoped symbol KSM specified twice"));
+	new.scoped_symbols = vec!["KSM".into(), "NOPE".into()];
+	assert!(new.validate().unwrap_err().contains("scoped symbol NOPE is not used"));
+	new.scoped_symbols.pop();
+
+	let descriptions: Vec<_> =
+		diff::diff(&old, &new).changes.iter().map(|c| c.to_string()).collect();
+	assert!(descriptions.contains(&"Removed token `KSM`".to_owned()));
+}
+
+#[test]
+fn native_token() {
+	use Ss58AddressFormatRegistry::*;
+
+	let cord = CordAccount;
+	assert_eq!(cord.native_token().map(|t| t.symbol), Some("DHI"));
+	assert_eq!(cord.token_for_balances_instance(1).map(|t| (t.symbol, t.index)), Some(("WAY", 1)));
+	assert_eq!(cord.token_for_balances_instance(2), None);
+	assert_eq!(PolkadotAccount.native_token().and_then(|t| t.token()), Some(TokenRegistry::Dot));
+	assert_eq!(Reserved46Account.native_token(), None);
+	assert_eq!(SubstrateAccount.native_token(), None);
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn caip_ids() {
+	use super::{Caip10AccountId, Caip2ChainId, CaipError};
+
+	let kusama = Ss58AddressFormatRegistry::KusamaAccount;
+	let chain = kusama.caip2_chain_ids()[0];
+	assert_eq!(chain.to_string(), "polkadot:b0a8d493285c2df73290dfb7e61f870f");
+	assert_eq!(chain.to_string().parse(), Ok(chain));
+	assert_eq!(chain.network(), Some(kusama));
+	assert!(Ss58AddressFormatRegistry::SubstrateAccount.caip2_chain_ids().is_empty());
+
+	let id =
+		"polkadot:b0a8d493285c2df73290dfb7e61f870f:HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+	let account: Caip10AccountId = id.parse().unwrap();
+	assert_eq!((account.chain(), account.to_string()), (chain, id.to_owned()));
+	assert_eq!(chain.account(&account.account()), Ok(account));
+
+	let unknown: Caip2ChainId = "polkadot:00000000000000000000000000000000".parse().unwrap();
+	assert_eq!(unknown.network(), None);
+	assert_eq!(unknown.account(&[0; 32]), Err(CaipError::UnknownChain));
+	let generic = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	assert!(Caip10AccountId::new(unknown, generic).is_ok());
+	assert_eq!(Caip10AccountId::new(chain, generic), Err(CaipError::FormatMismatch));
+
+	let parse = |s: &str| s.parse::<Caip10AccountId>().unwrap_err();
+	assert_eq!(
+		parse("eip155:1:0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb"),
+		CaipError::InvalidNamespace
+	);
+	assert_eq!(parse("polkadot:B0A8D493285C2DF73290DFB7E61F870F:x"), CaipError::InvalidReference);
+	assert_eq!(
+		parse("polkadot:b0a8d493:HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"),
+		CaipError::InvalidReference
+	);
+	assert!(matches!(parse(&id[..id.len() - 1]), CaipError::Address(_)));
+}
+
+#[test]
+fn standard_accounts() {
+	use super::SignatureType;
+
+	assert_eq!(
+		Ss58AddressFormatRegistry::PolkadotAccount.standard_account(),
+		Some(SignatureType::Any25519)
+	);
+	assert_eq!(
+		Ss58AddressFormatRegistry::MoonbeamAccount.standard_account(),
+		Some(SignatureType::Secp256k1)
+	);
+	assert_eq!(Ss58AddressFormatRegistry::Reserved46Account.standard_account(), None);
+	assert!(Ss58AddressFormatRegistry::MoonbeamAccount.is_ethereum());
+	assert!(!Ss58AddressFormatRegistry::KusamaAccount.is_ethereum());
+}
+
+#[test]
+#[cfg(feature = "ethereum")]
+fn h160_accounts() {
+	use super::{EthereumError, H160};
+
+	for checksummed in [
+		"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+		"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+		"0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+		"0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+	] {
+		let account: H160 = checksummed.to_lowercase().parse().unwrap();
+		assert_eq!(account.to_string(), checksummed);
+		assert_eq!(checksummed.parse(), Ok(account));
+		assert_eq!(format!("0x{}", checksummed[2..].to_uppercase()).parse(), Ok(account));
+	}
+	assert_eq!(
+		"0xd1220a0cf47c7B9Be7A2E6BA89F429762e7b9aDb".parse::<H160>(),
+		Err(EthereumError::InvalidChecksum)
+	);
+	assert_eq!(
+		"5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse::<H160>(),
+		Err(EthereumError::InvalidAddress)
+	);
+	assert_eq!("0x5aAeb6053F".parse::<H160>(), Err(EthereumError::InvalidAddress));
+
+	// The public key of the secret key `1`.
+	let mut public = [0; 33];
+	public[0] = 0x02;
+	for (i, byte) in public[1..].iter_mut().enumerate() {
+		let hex = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+		*byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+	}
+	let expected = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf";
+	assert_eq!(H160::from_public_key(&public).unwrap().to_string(), expected);
+	assert_eq!(H160::from_public_key(&[4; 33]), Err(EthereumError::InvalidPublicKey));
+	assert_eq!(H160::from_public_key(&public[1..]), Err(EthereumError::InvalidPublicKey));
+
+	let moonbeam = Ss58AddressFormatRegistry::MoonbeamAccount;
+	let address = Ss58AddressFormat::from(moonbeam).encode(&public).unwrap();
+	assert_eq!(moonbeam.h160_from_ss58(&address).unwrap().to_string(), expected);
+	assert_eq!(
+		Ss58AddressFormatRegistry::MoonriverAccount.h160_from_ss58(&address),
+		Err(EthereumError::FormatMismatch)
+	);
+	assert_eq!(
+		Ss58AddressFormatRegistry::PolkadotAccount.h160_from_ss58(&address),
+		Err(EthereumError::NotSecp256k1)
+	);
+}
+
+#[test]
+#[cfg(feature = "ethereum")]
+fn h160_account_mapping() {
+	use super::{EthereumError, H160};
+
+	let account: H160 = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf".parse().unwrap();
+	let substrate = Ss58AddressFormatRegistry::SubstrateAccount.into();
+	let address = account.substrate_address(substrate).unwrap();
+	assert_eq!(address, "5DF49wLC7xSD64r8fyJ9FCGHcccJmrS5JH6cQydEEenRSWMu");
+	assert_eq!(Ss58AddressFormat::decode(&address).unwrap().1, account.substrate_account());
+
+	let mut truncated = [0xee; 32];
+	truncated[..20].copy_from_slice(account.as_bytes());
+	assert_eq!(H160::from_substrate_account(&truncated), account);
+	let address = Ss58AddressFormat::from(Ss58AddressFormatRegistry::KusamaAccount)
+		.encode(&truncated)
+		.unwrap();
+	assert_eq!(H160::from_substrate_address(&address), Ok(account));
+
+	let public = Ss58AddressFormat::from(Ss58AddressFormatRegistry::MoonbeamAccount)
+		.encode(&[2; 33])
+		.unwrap();
+	assert_eq!(H160::from_substrate_address(&public), Err(EthereumError::InvalidAccount));
+	assert!(matches!(H160::from_substrate_address("0x00"), Err(EthereumError::Address(_))));
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn substrate_uris() {
+	use super::{SubstrateUri, UriError};
+
+	let kusama = Ss58AddressFormatRegistry::KusamaAccount;
+	let address = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+	let uri = SubstrateUri::new(address, kusama.genesis_hashes()[0]).unwrap();
+	let text = "substrate:HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F:\
+		0xb0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe";
+	assert_eq!(uri.to_string(), text);
+	assert_eq!(text.parse(), Ok(uri.clone()));
+	assert_eq!(text.replace("b0a8d", "B0A8D").parse(), Ok(uri.clone()));
+	assert_eq!((uri.address(), uri.network()), (address, Some(kusama)));
+	assert_eq!(uri.format(), kusama.into());
+	assert_eq!(Ss58AddressFormat::from(kusama).encode(&uri.account()).unwrap(), address);
+
+	let unknown = [0; 32];
+	let generic = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	assert_eq!(SubstrateUri::new(generic, unknown).unwrap().network(), None);
+	assert_eq!(
+		SubstrateUri::new(generic, kusama.genesis_hashes()[0]),
+		Err(UriError::FormatMismatch {
+			address: Ss58AddressFormatRegistry::SubstrateAccount.into(),
+			network: kusama
+		})
+	);
+
+	let parse = |s: &str| s.parse::<SubstrateUri>().unwrap_err();
+	assert_eq!(parse(&text.replacen("substrate", "polkadot", 1)), UriError::InvalidScheme);
+	assert_eq!(parse(address), UriError::InvalidScheme);
+	assert_eq!(parse(&text[..text.len() - 2]), UriError::InvalidGenesisHash);
+	assert_eq!(parse(&text.replace(":0x", ":")), UriError::InvalidGenesisHash);
+	assert_eq!(parse(&format!("{}:extra", text)), UriError::InvalidGenesisHash);
+	assert!(matches!(parse(&text.replace("HNZ", "HNY")), UriError::Address(_)));
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn multisig_accounts() {
+	use super::{multisig_account, AddressError, MultisigError};
+
+	let substrate = Ss58AddressFormat::from(Ss58AddressFormatRegistry::SubstrateAccount);
+	let polkadot = Ss58AddressFormat::from(Ss58AddressFormatRegistry::PolkadotAccount);
+	let alice = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+	let bob = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+	let charlie = "5FLSigC9HGRKVhB9FiEo4Y3koPsNmBmLJbpXg2mp1hXcS59Y";
+	let expected = "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7";
+	assert_eq!(substrate.multisig_address(&[alice, bob, charlie], 2).unwrap(), expected);
+	assert_eq!(substrate.multisig_address(&[charlie, alice, bob], 2).unwrap(), expected);
+	assert_eq!(
+		polkadot.multisig_address(&[bob, charlie, alice], 2).unwrap(),
+		"12fqSn9qVLJL4NY7Uua7bexEAVr9oCpD3e5xmdpNjtQszzBt"
+	);
+	assert_ne!(substrate.multisig_address(&[alice, bob, charlie], 3).unwrap(), expected);
+
+	// More than 63 signatories use the two byte compact length.
+	let signatories: Vec<[u8; 32]> = (0..64).rev().map(|i| [i; 32]).collect();
+	let account = multisig_account(&signatories, 40).unwrap();
+	assert_eq!(
+		substrate.encode(&account).unwrap(),
+		"5D4i4iFiveSBxY2LRcvSkMibYwGm5Acn8PptzpfgJBj4AbdJ"
+	);
+
+	assert_eq!(multisig_account(&[[1; 32]], 1), Err(MultisigError::TooFewSignatories));
+	assert_eq!(multisig_account(&[[1; 32], [2; 32]], 0), Err(MultisigError::InvalidThreshold));
+	assert_eq!(multisig_account(&[[1; 32], [2; 32]], 3), Err(MultisigError::InvalidThreshold));
+	assert_eq!(
+		multisig_account(&[[1; 32], [2; 32], [1; 32]], 2),
+		Err(MultisigError::DuplicateSignatory)
+	);
+	let moonbeam = Ss58AddressFormat::from(Ss58AddressFormatRegistry::MoonbeamAccount);
+	let ecdsa = moonbeam.encode(&[2; 33]).unwrap();
+	assert_eq!(substrate.multisig_address(&[alice, &ecdsa], 1), Err(MultisigError::InvalidAccount));
+	assert_eq!(
+		substrate.multisig_address(&[alice, "x"], 1),
+		Err(MultisigError::Address(AddressError::BadLength))
+	);
+}
+
+#[cfg(feature = "ss58")]
+#[test]
+fn derived_accounts() {
+	use super::DerivedAccount;
+
+	let polkadot = Ss58AddressFormat::from(Ss58AddressFormatRegistry::PolkadotAccount);
+	let kusama = Ss58AddressFormat::from(Ss58AddressFormatRegistry::KusamaAccount);
+	let treasury = DerivedAccount::Pallet(*b"py/trsry");
+	assert_eq!(&treasury.account()[..12], b"modlpy/trsry");
+	assert_eq!(treasury.account()[12..], [0; 20]);
+	assert_eq!(
+		treasury.address(polkadot).unwrap(),
+		"13UVJyLnbVp9RBZYFwFGyDvVd1y27Tt8tkntv6Q7JVPhFsTB"
+	);
+	assert_eq!(
+		treasury.address(kusama).unwrap(),
+		"F3opxRbN5ZbjJNU511Kj2TLuzFcDq9BGduA9TgiECafpg29"
+	);
+
+	let acala = DerivedAccount::Parachain(2000);
+	assert_eq!(&acala.account()[..8], b"para\xd0\x07\0\0");
+	assert_eq!(
+		acala.address(polkadot).unwrap(),
+		"13YMK2eYoAvStnzReuxBjMrAvPXmmdsURwZvc62PrdXimbNy"
+	);
+	assert_eq!(&DerivedAccount::Sibling(2000).account()[..8], b"sibl\xd0\x07\0\0");
+	assert_eq!(
+		Ss58AddressFormatRegistry::AcalaAccount.sovereign_address(),
+		Some(acala.address(polkadot).unwrap())
+	);
+	assert_eq!(Ss58AddressFormatRegistry::PolkadotAccount.sovereign_address(), None);
+
+	let (_, alice) =
+		Ss58AddressFormat::decode("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap();
+	let who = alice.try_into().unwrap();
+	assert_eq!(
+		DerivedAccount::Derivative { who, index: 0 }.address(polkadot).unwrap(),
+		"13kQEUR8BMMtHpZKMK3A9pHfH4XUMKh4n8KzSom74rjacxii"
+	);
+	assert_ne!(
+		DerivedAccount::Derivative { who, index: 1 }.account(),
+		DerivedAccount::Derivative { who, index: 0 }.account()
+	);
+}
@@ -13,19 +13,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::ParseAmountError;
 #[cfg(feature = "std")]
 use num_format::{Locale, ToFormattedString};
 
 /// Name and decimals of a given token.
+///
+/// The name is `&'static str` for tokens known at compile time. Tokens discovered at runtime, e.g.
+/// from chain metadata, can use an owned name such as `String` or `Cow<'static, str>` and get the
+/// same formatting and conversions.
+///
+/// ```
+/// # use ss58_registry::Token;
+/// # #[cfg(feature = "std")]
+/// # fn x() {
+/// let token = Token { name: String::from("USDT"), decimals: 6 };
+/// assert_eq!(token.amount(1_500_000).to_string(), "1.500 USDT");
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn x() {}
+/// # x();
+/// ```
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Token {
+pub struct Token<N = &'static str> {
 	/// The short name (ticker) of the token
-	pub name: &'static str,
+	pub name: N,
 	/// The number of decimals the token has (smallest granularity of the token)
 	pub decimals: u8,
 }
 
-impl core::fmt::Debug for Token {
+/// A [`Token`] with a runtime-owned name.
+#[cfg(feature = "std")]
+pub type OwnedToken = Token<String>;
+
+impl<N: core::fmt::Debug> core::fmt::Debug for Token<N> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_struct("Token")
 			.field("name", &self.name)
@@ -34,7 +55,20 @@ impl core::fmt::Debug for Token {
 	}
 }
 
-impl Token {
+impl<N: AsRef<str>> Token<N> {
+	/// Borrows the token, e.g. to compare tokens with different name storage.
+	pub fn as_borrowed(&self) -> Token<&str> {
+		Token { name: self.name.as_ref(), decimals: self.decimals }
+	}
+
+	/// Converts the token into one owning its name.
+	#[cfg(feature = "std")]
+	pub fn into_owned(self) -> OwnedToken {
+		Token { name: self.name.as_ref().to_owned(), decimals: self.decimals }
+	}
+}
+
+impl<N: Clone> Token<N> {
 	/// Creates the specified amount of [`Token`] with its name and decimals filled from the
 	/// [`TokenRegistry`] variant.
 	///
@@ -51,9 +85,240 @@ impl Token {
 	/// # fn x() {}
 	/// # x();
 	/// ```
-	pub fn amount(&self, amount: u128) -> TokenAmount {
+	pub fn amount(&self, amount: u128) -> TokenAmount<N> {
 		TokenAmount { token: self.clone(), amount }
 	}
+
+	/// Creates a signed change of the specified amount of [`Token`].
+	pub fn delta(&self, delta: i128) -> TokenDelta<N> {
+		TokenDelta { token: self.clone(), delta }
+	}
+
+	/// Parses an amount of [`Token`] in whole units, as rendered by the [`Display`]
+	/// implementation of [`TokenAmount`]. Thousands separators and the token symbol are
+	/// optional.
+	///
+	/// ```
+	/// # use ss58_registry::{ParseAmountError, Token, TokenRegistry};
+	/// let token: Token = TokenRegistry::Dot.into();
+	/// assert_eq!(token.parse_amount("1,000.25 DOT").map(|a| a.amount), Ok(10_002_500_000_000));
+	/// assert_eq!(token.parse_amount("0.00000000001").err(), Some(ParseAmountError::TooManyDecimals));
+	/// assert_eq!(token.parse_amount("1 KSM").err(), Some(ParseAmountError::TokenMismatch));
+	/// ```
+	///
+	/// [`Display`]: core::fmt::Display
+	pub fn parse_amount(&self, s: &str) -> Result<TokenAmount<N>, ParseAmountError>
+	where
+		N: AsRef<str>,
+	{
+		let s = s.trim();
+		let s = s.strip_prefix('+').unwrap_or(s);
+		Ok(self.amount(self.parse_planck(s)?))
+	}
+
+	/// Parses a signed change of [`Token`] in whole units, as rendered by the [`Display`]
+	/// implementation of [`TokenDelta`].
+	///
+	/// ```
+	/// # use ss58_registry::{Token, TokenRegistry};
+	/// let token: Token = TokenRegistry::Dot.into();
+	/// assert_eq!(token.parse_delta("-0.250 DOT").map(|d| d.delta), Ok(-2_500_000_000));
+	/// assert_eq!(token.parse_delta("+12").map(|d| d.delta), Ok(120_000_000_000));
+	/// ```
+	///
+	/// [`Display`]: core::fmt::Display
+	pub fn parse_delta(&self, s: &str) -> Result<TokenDelta<N>, ParseAmountError>
+	where
+		N: AsRef<str>,
+	{
+		let s = s.trim();
+		let (negative, s) = match s.strip_prefix('-') {
+			Some(rest) => (true, rest),
+			None => (false, s.strip_prefix('+').unwrap_or(s)),
+		};
+		let planck = self.parse_planck(s)?;
+		let delta = if negative {
+			0_i128.checked_sub_unsigned(planck)
+		} else {
+			i128::try_from(planck).ok()
+		};
+		Ok(self.delta(delta.ok_or(ParseAmountError::Overflow)?))
+	}
+
+	/// Parses an unsigned decimal number of whole units, optionally followed by the symbol of
+	/// the token, into planck.
+	fn parse_planck(&self, s: &str) -> Result<u128, ParseAmountError>
+	where
+		N: AsRef<str>,
+	{
+		let (number, symbol) = match s.split_once(char::is_whitespace) {
+			Some((number, symbol)) => (number, Some(symbol.trim_start())),
+			None => (s, None),
+		};
+		if let Some(symbol) = symbol {
+			if symbol != self.name.as_ref() {
+				return Err(ParseAmountError::TokenMismatch)
+			}
+		}
+		let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+		if whole.is_empty() && fraction.is_empty() {
+			return Err(ParseAmountError::InvalidNumber)
+		}
+		if fraction.len() > usize::from(self.decimals) {
+			return Err(ParseAmountError::TooManyDecimals)
+		}
+
+		// Thousands separators as rendered by `Display`: if any, every group after the first has
+		// exactly three digits.
+		let mut groups = whole.split(',');
+		let first = groups.next().unwrap_or_default();
+		if whole.contains(',') &&
+			(first.is_empty() || first.len() > 3 || groups.any(|g| g.len() != 3))
+		{
+			return Err(ParseAmountError::InvalidNumber)
+		}
+
+		let mut planck = 0_u128;
+		let mut push_digit = |c: char| {
+			let digit = c.to_digit(10).ok_or(ParseAmountError::InvalidNumber)?;
+			planck = planck
+				.checked_mul(10)
+				.and_then(|p| p.checked_add(digit.into()))
+				.ok_or(ParseAmountError::Overflow)?;
+			Ok(())
+		};
+		for c in whole.chars().filter(|c| *c != ',') {
+			push_digit(c)?;
+		}
+		for c in fraction.chars() {
+			push_digit(c)?;
+		}
+		let padding = u128::pow(10, u32::from(self.decimals) - fraction.len() as u32);
+		planck.checked_mul(padding).ok_or(ParseAmountError::Overflow)
+	}
+
+	/// Creates the amount of [`Token`] corresponding to `value` whole units. Returns `None` if
+	/// `value` is negative, has more fractional digits than the token has decimals or overflows.
+	#[cfg(feature = "rust_decimal")]
+	pub fn amount_from_decimal(&self, value: rust_decimal::Decimal) -> Option<TokenAmount<N>> {
+		let value = value.normalize();
+		let padding = u32::from(self.decimals).checked_sub(value.scale())?;
+		let mantissa = u128::try_from(value.mantissa()).ok()?;
+		let amount = mantissa.checked_mul(10_u128.checked_pow(padding)?)?;
+		Some(self.amount(amount))
+	}
+
+	/// Creates the amount of [`Token`] corresponding to `value` whole units, rounded to the nearest
+	/// planck. Negative values saturate to zero.
+	///
+	/// ```
+	/// # use ss58_registry::{Token, TokenRegistry};
+	/// let token: Token = TokenRegistry::Dot.into();
+	/// assert_eq!(token.units(1.5).amount, 15_000_000_000);
+	/// assert_eq!(token.milli(2.0).amount, 20_000_000);
+	/// ```
+	pub fn units(&self, value: f64) -> TokenAmount<N> {
+		self.denominated(value, Denomination::Unit)
+	}
+
+	/// Creates the amount of [`Token`] corresponding to `value` milli units.
+	pub fn milli(&self, value: f64) -> TokenAmount<N> {
+		self.denominated(value, Denomination::Milli)
+	}
+
+	/// Creates the amount of [`Token`] corresponding to `value` micro units.
+	pub fn micro(&self, value: f64) -> TokenAmount<N> {
+		self.denominated(value, Denomination::Micro)
+	}
+
+	/// Creates the amount of [`Token`] corresponding to `value` of the given [`Denomination`],
+	/// rounded to the nearest planck. Negative values saturate to zero.
+	pub fn denominated(&self, value: f64, denomination: Denomination) -> TokenAmount<N> {
+		let planck = value * pow10(denomination.exponent(self));
+		self.amount(round(planck))
+	}
+}
+
+/// Round half up to a number of planck, saturating at zero and `u128::MAX` (`f64::round` is
+/// not available without `std`).
+fn round(x: f64) -> u128 {
+	let truncated = x as u128;
+	if x - truncated as f64 >= 0.5 {
+		truncated.saturating_add(1)
+	} else {
+		truncated
+	}
+}
+
+/// `10^exp` (`f64::powi` is not available without `std`).
+fn pow10(exp: i32) -> f64 {
+	let mut result = 1.0;
+	for _ in 0..exp.unsigned_abs() {
+		result *= 10.0;
+	}
+	if exp < 0 {
+		1.0 / result
+	} else {
+		result
+	}
+}
+
+/// A unit in which an amount of some [`Token`] can be expressed.
+///
+/// The standard denominations are derived from the token's decimals. Networks can declare
+/// additional named denominations in the registry, see [`TokenRegistry::denominations`].
+///
+/// [`TokenRegistry::denominations`]: crate::TokenRegistry::denominations
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Denomination {
+	/// The smallest granularity of the token.
+	Planck,
+	/// A millionth of a whole unit.
+	Micro,
+	/// A thousandth of a whole unit.
+	Milli,
+	/// One whole unit of the token.
+	Unit,
+	/// A named denomination made up of `10^decimals` planck.
+	Custom {
+		/// Name of the denomination, e.g. `Point`.
+		name: &'static str,
+		/// Number of decimals, i.e. one of this denomination is `10^decimals` planck.
+		decimals: u8,
+	},
+}
+
+impl Denomination {
+	/// Power of ten of planck making up one of this denomination. May be negative for tokens with
+	/// fewer decimals than the denomination assumes, e.g. [`Denomination::Micro`] of a token
+	/// with 2 decimals.
+	pub fn exponent<N>(&self, token: &Token<N>) -> i32 {
+		let decimals = i32::from(token.decimals);
+		match self {
+			Denomination::Planck => 0,
+			Denomination::Micro => decimals - 6,
+			Denomination::Milli => decimals - 3,
+			Denomination::Unit => decimals,
+			Denomination::Custom { decimals, .. } => i32::from(*decimals),
+		}
+	}
+}
+
+/// Splits `amount` planck into the whole number of `10^exponent` and thousandths thereof.
+#[cfg(feature = "std")]
+fn split_thousandths(amount: u128, exponent: i32) -> (u128, u128) {
+	if exponent < 0 {
+		(amount.saturating_mul(u128::pow(10, exponent.unsigned_abs())), 0)
+	} else {
+		let multiplier = u128::pow(10, exponent as u32);
+		let rest = amount % multiplier;
+		let thousandths = if exponent >= 3 {
+			rest / u128::pow(10, exponent as u32 - 3)
+		} else {
+			rest * u128::pow(10, 3 - exponent as u32)
+		};
+		(amount / multiplier, thousandths)
+	}
 }
 
 /// A given amount of token. Can be used for nicely formatted output and token-aware comparison of
@@ -73,38 +338,422 @@ impl Token {
 /// # x();
 /// ```
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TokenAmount {
+pub struct TokenAmount<N = &'static str> {
 	/// The token this amount is from.
-	pub token: Token,
+	pub token: Token<N>,
 	/// The amount in the smallest granularity of the token.
 	pub amount: u128,
 }
 
+impl<N> TokenAmount<N> {
+	/// The amount in planck, the smallest granularity of the token.
+	pub fn as_planck(&self) -> u128 {
+		self.amount
+	}
+
+	/// Applies a change of the same token to the amount. Returns `None` if the tokens differ or
+	/// the result would be negative or overflow.
+	pub fn checked_apply(&self, delta: &TokenDelta<N>) -> Option<Self>
+	where
+		N: Clone + PartialEq,
+	{
+		if self.token != delta.token {
+			return None
+		}
+		let amount = if delta.delta < 0 {
+			self.amount.checked_sub(delta.delta.unsigned_abs())
+		} else {
+			self.amount.checked_add(delta.delta.unsigned_abs())
+		}?;
+		Some(self.token.amount(amount))
+	}
+
+	/// The amount in micro units.
+	pub fn as_micro(&self) -> f64 {
+		self.as_denomination(Denomination::Micro)
+	}
+
+	/// The amount in milli units.
+	pub fn as_milli(&self) -> f64 {
+		self.as_denomination(Denomination::Milli)
+	}
+
+	/// The amount in whole units.
+	pub fn as_units(&self) -> f64 {
+		self.as_denomination(Denomination::Unit)
+	}
+
+	/// The amount expressed in the given [`Denomination`].
+	pub fn as_denomination(&self, denomination: Denomination) -> f64 {
+		self.amount as f64 / pow10(denomination.exponent(&self.token))
+	}
+
+	/// The exact amount in whole units as a decimal string without thousands separators or
+	/// trailing zeros. [`Token::parse_amount`] converts it back.
+	///
+	/// ```
+	/// # use ss58_registry::{Token, TokenRegistry};
+	/// let token: Token = TokenRegistry::Dot.into();
+	/// let my_amount = token.amount(12_345_000_000_001);
+	/// assert_eq!(my_amount.to_decimal_string(), "1234.5000000001");
+	/// assert_eq!(token.parse_amount(&my_amount.to_decimal_string()), Ok(my_amount));
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn to_decimal_string(&self) -> String {
+		let decimals = usize::from(self.token.decimals);
+		let multiplier = u128::pow(10, decimals as u32);
+		let (whole, fraction) = (self.amount / multiplier, self.amount % multiplier);
+		if fraction == 0 {
+			whole.to_string()
+		} else {
+			let fraction = format!("{:0>width$}", fraction, width = decimals);
+			format!("{}.{}", whole, fraction.trim_end_matches('0'))
+		}
+	}
+
+	/// The amount in whole units as the nearest `f64`.
+	///
+	/// The result is correctly rounded from the exact decimal value, so its relative error is at
+	/// most 2^-53 (about 1.1e-16). Amounts above 2^53 planck, e.g. more than about 0.009 whole
+	/// units of an 18 decimal token or 900,719 DOT (10 decimals), are therefore generally not
+	/// exact down to the planck. Use [`TokenAmount::to_decimal_string`] for lossless conversions.
+	#[cfg(feature = "std")]
+	pub fn to_f64_lossy(&self) -> f64 {
+		self.to_decimal_string().parse().expect("decimal string is a valid float")
+	}
+
+	/// Converts the amount in whole units to a [`rust_decimal::Decimal`]. Returns `None` if the
+	/// amount does not fit the 96 bit mantissa and 28 digit scale of `Decimal` without loss.
+	#[cfg(feature = "rust_decimal")]
+	pub fn to_decimal(&self) -> Option<rust_decimal::Decimal> {
+		let (mut mantissa, mut scale) = (self.amount, u32::from(self.token.decimals));
+		// Drop trailing zeros until the amount fits.
+		loop {
+			let decimal = i128::try_from(mantissa)
+				.ok()
+				.and_then(|m| rust_decimal::Decimal::try_from_i128_with_scale(m, scale).ok());
+			if decimal.is_some() || scale == 0 || mantissa % 10 != 0 {
+				return decimal
+			}
+			mantissa /= 10;
+			scale -= 1;
+		}
+	}
+
+	/// Renders the amount in the given [`Denomination`].
+	///
+	/// ```
+	/// # use ss58_registry::{Denomination, Token, TokenRegistry};
+	/// # #[cfg(feature = "std")]
+	/// # fn x() {
+	/// let token: Token = TokenRegistry::Ksm.into();
+	/// let point = TokenRegistry::Ksm.denominations()[0];
+	/// let my_amount = token.units(1.5);
+	/// assert_eq!(my_amount.display_in(Denomination::Milli).to_string(), "1,500.000 mKSM");
+	/// assert_eq!(my_amount.display_in(point).to_string(), "1,500.000 Point");
+	/// assert_eq!(my_amount.display_in(Denomination::Planck).to_string(), "1,500,000,000,000 planck");
+	/// # }
+	/// # #[cfg(not(feature = "std"))]
+	/// # fn x() {}
+	/// # x();
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn display_in(&self, denomination: Denomination) -> DenominatedAmount<'_, N> {
+		DenominatedAmount { amount: self, denomination }
+	}
+
+	/// Renders the amount abbreviated with a magnitude suffix, e.g. `12.3M DOT`. Defaults to three
+	/// significant digits and [`CompactSuffixes::Short`]. Like the [`Display`] implementation,
+	/// surplus digits are truncated rather than rounded.
+	///
+	/// ```
+	/// # use ss58_registry::{CompactSuffixes, Token, TokenRegistry};
+	/// let token: Token = TokenRegistry::Dot.into();
+	/// let my_amount = token.amount(123_456_789_000_000_000);
+	/// assert_eq!(my_amount.compact().to_string(), "12.3M DOT");
+	/// assert_eq!(my_amount.compact().significant_digits(5).to_string(), "12.345M DOT");
+	/// assert_eq!(token.units(1.05e9).compact().suffixes(CompactSuffixes::Si).to_string(), "1.05G DOT");
+	/// assert_eq!(token.amount(12_345_678).compact().to_string(), "0.00123 DOT");
+	/// ```
+	///
+	/// [`Display`]: core::fmt::Display
+	pub fn compact(&self) -> CompactAmount<'_, N> {
+		CompactAmount { amount: self, significant_digits: 3, suffixes: CompactSuffixes::Short }
+	}
+}
+
+/// Magnitude suffixes used by [`CompactAmount`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum CompactSuffixes {
+	/// `K`, `M`, `B` and `T` as commonly used for financial amounts.
+	Short,
+	/// SI prefixes `k`, `M`, `G`, `T`, `P` and `E`.
+	Si,
+	/// Custom suffixes where the `n`th entry denotes `10^(3 * (n + 1))`.
+	Custom(&'static [&'static str]),
+}
+
+impl CompactSuffixes {
+	fn as_slice(&self) -> &'static [&'static str] {
+		match self {
+			CompactSuffixes::Short => &["K", "M", "B", "T"],
+			CompactSuffixes::Si => &["k", "M", "G", "T", "P", "E"],
+			CompactSuffixes::Custom(suffixes) => suffixes,
+		}
+	}
+}
+
+/// A signed change of some amount of token, e.g. a credit or debit of an account balance. Formats
+/// like [`TokenAmount`] with an explicit sign.
+///
+/// ```
+/// # use ss58_registry::{Token, TokenDelta, TokenRegistry};
+/// # #[cfg(feature = "std")]
+/// # fn x() {
+/// let token: Token = TokenRegistry::Ksm.into();
+/// let credit = TokenDelta::from_amounts(token.units(12.0), token.amount(0)).unwrap();
+/// assert_eq!(format!("{}", credit), "+12.000 KSM");
+/// let debit = token.delta(-250_000_000_000);
+/// assert_eq!(format!("{}", debit), "-0.250 KSM");
+/// assert_eq!(format!("{:?}", debit), "-0.250 KSM (-250,000,000,000)");
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn x() {}
+/// # x();
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokenDelta<N = &'static str> {
+	/// The token this change is of.
+	pub token: Token<N>,
+	/// The change in the smallest granularity of the token.
+	pub delta: i128,
+}
+
+impl<N> TokenDelta<N> {
+	/// Whether this is a debit.
+	pub fn is_negative(&self) -> bool {
+		self.delta < 0
+	}
+
+	/// Creates the change from crediting `credit` and debiting `debit`. Returns `None` if the
+	/// tokens differ or the difference does not fit the delta.
+	pub fn from_amounts(credit: TokenAmount<N>, debit: TokenAmount<N>) -> Option<Self>
+	where
+		N: PartialEq,
+	{
+		if credit.token != debit.token {
+			return None
+		}
+		let delta = if credit.amount >= debit.amount {
+			i128::try_from(credit.amount - debit.amount).ok()?
+		} else {
+			0_i128.checked_sub_unsigned(debit.amount - credit.amount)?
+		};
+		Some(TokenDelta { token: credit.token, delta })
+	}
+
+	/// Splits the change into a `(credit, debit)` pair of amounts, one of which is zero.
+	pub fn into_amounts(self) -> (TokenAmount<N>, TokenAmount<N>)
+	where
+		N: Clone,
+	{
+		let magnitude = self.delta.unsigned_abs();
+		if self.is_negative() {
+			(self.token.amount(0), self.token.amount(magnitude))
+		} else {
+			(self.token.amount(magnitude), self.token.amount(0))
+		}
+	}
+
+	/// The absolute amount of the change.
+	pub fn magnitude(&self) -> TokenAmount<N>
+	where
+		N: Clone,
+	{
+		self.token.amount(self.delta.unsigned_abs())
+	}
+}
+
+/// A [`TokenAmount`] rendered in abbreviated form, see [`TokenAmount::compact`].
+pub struct CompactAmount<'a, N = &'static str> {
+	amount: &'a TokenAmount<N>,
+	significant_digits: u8,
+	suffixes: CompactSuffixes,
+}
+
+impl<N> Clone for CompactAmount<'_, N> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<N> Copy for CompactAmount<'_, N> {}
+
+impl<N> CompactAmount<'_, N> {
+	/// Sets the number of significant digits to show (at least one). Digits of the integer part
+	/// are never dropped.
+	pub fn significant_digits(mut self, digits: u8) -> Self {
+		self.significant_digits = digits.max(1);
+		self
+	}
+
+	/// Sets the suffixes to abbreviate the amount with.
+	pub fn suffixes(mut self, suffixes: CompactSuffixes) -> Self {
+		self.suffixes = suffixes;
+		self
+	}
+}
+
+/// Number of decimal digits of `x`.
+fn digits(mut x: u128) -> u32 {
+	let mut digits = 1;
+	while x >= 10 {
+		x /= 10;
+		digits += 1;
+	}
+	digits
+}
+
+impl<N: AsRef<str>> core::fmt::Display for CompactAmount<'_, N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let TokenAmount { token, amount } = self.amount;
+		let decimals = u32::from(token.decimals);
+		let amount_digits = digits(*amount);
+		let integer_digits = amount_digits.saturating_sub(decimals);
+
+		let suffixes = self.suffixes.as_slice();
+		let tier = (integer_digits.saturating_sub(1) / 3).min(suffixes.len() as u32);
+		let exponent = decimals + 3 * tier;
+
+		let significant_digits = u32::from(self.significant_digits);
+		let fraction_digits = if *amount == 0 {
+			0
+		} else if amount_digits > exponent {
+			significant_digits.saturating_sub(amount_digits - exponent)
+		} else {
+			significant_digits + (exponent - amount_digits)
+		}
+		.min(exponent);
+
+		let shown = amount / u128::pow(10, exponent - fraction_digits);
+		let multiplier = u128::pow(10, fraction_digits);
+		let (whole, mut fraction) = (shown / multiplier, shown % multiplier);
+		let mut fraction_digits = fraction_digits as usize;
+		while fraction_digits > 0 && fraction % 10 == 0 {
+			fraction /= 10;
+			fraction_digits -= 1;
+		}
+
+		write!(f, "{}", whole)?;
+		if fraction_digits > 0 {
+			write!(f, ".{:0>width$}", fraction, width = fraction_digits)?;
+		}
+		if tier > 0 {
+			write!(f, "{}", suffixes[tier as usize - 1])?;
+		}
+		write!(f, " {}", token.name.as_ref())
+	}
+}
+
+/// A [`TokenAmount`] rendered in a specific [`Denomination`], see [`TokenAmount::display_in`].
+#[cfg(feature = "std")]
+pub struct DenominatedAmount<'a, N = &'static str> {
+	amount: &'a TokenAmount<N>,
+	denomination: Denomination,
+}
+
+#[cfg(feature = "std")]
+impl<N> Clone for DenominatedAmount<'_, N> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
 #[cfg(feature = "std")]
-impl std::fmt::Display for TokenAmount {
+impl<N> Copy for DenominatedAmount<'_, N> {}
+
+#[cfg(feature = "std")]
+impl<N: AsRef<str>> std::fmt::Display for DenominatedAmount<'_, N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let name = self.amount.token.name.as_ref();
+		if let Denomination::Planck = self.denomination {
+			return write!(f, "{} planck", self.amount.amount.to_formatted_string(&Locale::en))
+		}
+		let (whole, thousandths) =
+			split_thousandths(self.amount.amount, self.denomination.exponent(&self.amount.token));
+		write!(f, "{}.{:0>3} ", whole.to_formatted_string(&Locale::en), thousandths)?;
+		match self.denomination {
+			Denomination::Micro => write!(f, "µ{}", name),
+			Denomination::Milli => write!(f, "m{}", name),
+			Denomination::Custom { name, .. } => write!(f, "{}", name),
+			Denomination::Planck | Denomination::Unit => write!(f, "{}", name),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<N: AsRef<str>> std::fmt::Display for TokenAmount<N> {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		let multiplier = u128::pow(10, self.token.decimals as u32);
+		let (whole, thousandths) = split_thousandths(self.amount, self.token.decimals.into());
 		write!(
 			f,
 			"{}.{:0>3} {}",
-			(self.amount / multiplier).to_formatted_string(&Locale::en),
-			self.amount % multiplier / (multiplier / 1000),
-			self.token.name
+			whole.to_formatted_string(&Locale::en),
+			thousandths,
+			self.token.name.as_ref()
 		)
 	}
 }
 
 #[cfg(feature = "std")]
-impl std::fmt::Debug for TokenAmount {
+impl<N: AsRef<str>> std::fmt::Debug for TokenAmount<N> {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		let multiplier = u128::pow(10, self.token.decimals as u32);
+		let (whole, thousandths) = split_thousandths(self.amount, self.token.decimals.into());
 		write!(
 			f,
 			"{}.{:0>3} {} ({})",
-			self.amount / multiplier,
-			self.amount % multiplier / (multiplier / 1000),
-			self.token.name,
+			whole,
+			thousandths,
+			self.token.name.as_ref(),
 			self.amount.to_formatted_string(&Locale::en),
 		)
 	}
 }
+
+#[cfg(feature = "std")]
+impl<N: AsRef<str>> std::fmt::Display for TokenDelta<N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let sign = match self.delta {
+			0 => "",
+			d if d < 0 => "-",
+			_ => "+",
+		};
+		let (whole, thousandths) =
+			split_thousandths(self.delta.unsigned_abs(), self.token.decimals.into());
+		write!(
+			f,
+			"{}{}.{:0>3} {}",
+			sign,
+			whole.to_formatted_string(&Locale::en),
+			thousandths,
+			self.token.name.as_ref()
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<N: AsRef<str>> std::fmt::Debug for TokenDelta<N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let (whole, thousandths) =
+			split_thousandths(self.delta.unsigned_abs(), self.token.decimals.into());
+		write!(
+			f,
+			"{}{}.{:0>3} {} ({})",
+			if self.delta < 0 { "-" } else { "" },
+			whole,
+			thousandths,
+			self.token.name.as_ref(),
+			self.delta.to_formatted_string(&Locale::en),
+		)
+	}
+}
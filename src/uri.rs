@@ -0,0 +1,111 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `substrate:<address>:<genesis hash>` URIs, as exchanged in QR codes by Polkadot Vault and
+//! wallets to share an account on a specific chain.
+
+use super::*;
+use crate::hex_decode::decode_hex;
+use core::{fmt, str::FromStr};
+
+const SCHEME: &str = "substrate";
+
+/// An account on a chain, like
+/// `substrate:15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5:0x91b1...90c3`.
+///
+/// ```
+/// # use ss58_registry::{Ss58AddressFormatRegistry, SubstrateUri};
+/// let polkadot = Ss58AddressFormatRegistry::PolkadotAccount;
+/// let uri = SubstrateUri::new(
+///     "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+///     polkadot.genesis_hashes()[0],
+/// )
+/// .unwrap();
+/// assert_eq!(uri.network(), Some(polkadot));
+/// assert_eq!(uri.to_string().parse(), Ok(uri));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SubstrateUri {
+	address: String,
+	genesis_hash: [u8; 32],
+}
+
+impl SubstrateUri {
+	/// Combines the SS58 address and the genesis hash, checking the address is valid and, if the
+	/// chain is in the registry, uses the format of its network.
+	pub fn new(address: &str, genesis_hash: [u8; 32]) -> Result<Self, UriError> {
+		let (format, _) = Ss58AddressFormat::decode(address)?;
+		match Ss58AddressFormatRegistry::lookup_by_genesis(&genesis_hash) {
+			Some(network) if Ss58AddressFormat::from(network) != format =>
+				Err(UriError::FormatMismatch { address: format, network }),
+			_ => Ok(SubstrateUri { address: address.to_owned(), genesis_hash }),
+		}
+	}
+
+	/// The SS58 address of the account.
+	pub fn address(&self) -> &str {
+		&self.address
+	}
+
+	/// The address format of the account.
+	pub fn format(&self) -> Ss58AddressFormat {
+		let (format, _) = Ss58AddressFormat::decode(&self.address).expect("checked on creation");
+		format
+	}
+
+	/// The account data encoded in the address.
+	pub fn account(&self) -> Vec<u8> {
+		let (_, account) = Ss58AddressFormat::decode(&self.address).expect("checked on creation");
+		account
+	}
+
+	/// Genesis hash of the chain.
+	pub fn genesis_hash(&self) -> [u8; 32] {
+		self.genesis_hash
+	}
+
+	/// The network of the chain, if its genesis hash is in the registry.
+	pub fn network(&self) -> Option<Ss58AddressFormatRegistry> {
+		Ss58AddressFormatRegistry::lookup_by_genesis(&self.genesis_hash)
+	}
+}
+
+impl fmt::Display for SubstrateUri {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}:{}:0x", SCHEME, self.address)?;
+		self.genesis_hash.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+	}
+}
+
+impl FromStr for SubstrateUri {
+	type Err = UriError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.split(':');
+		if parts.next() != Some(SCHEME) {
+			return Err(UriError::InvalidScheme)
+		}
+		let address = parts.next().ok_or(UriError::InvalidScheme)?;
+		let genesis = parts.next().ok_or(UriError::InvalidGenesisHash)?;
+		if parts.next().is_some() {
+			return Err(UriError::InvalidGenesisHash)
+		}
+		let genesis_hash = genesis
+			.strip_prefix("0x")
+			.and_then(decode_hex)
+			.ok_or(UriError::InvalidGenesisHash)?;
+		SubstrateUri::new(address, genesis_hash)
+	}
+}
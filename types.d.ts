@@ -21,6 +21,15 @@ export interface RegistryEntry {
 	standardAccount: '*25519' | 'Ed25519' | 'Sr25519' | 'secp256k1' | null;
 	symbols: string[];
 	website: string | null;
+	genesisHashes?: string[];
+	relayChain?: string;
+	paraId?: number;
+	status?: 'mainnet' | 'testnet' | 'deprecated' | 'reserved';
+	explorer?: {
+		address?: string;
+		block?: string;
+		extrinsic?: string;
+	};
 }
 
 export type Registry = RegistryEntry[];